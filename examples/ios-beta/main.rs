@@ -40,7 +40,7 @@ impl ViewDelegate for RootView {
 
         let image_bytes = include_bytes!("../../test-data/favicon.ico");
         self.image = ImageView::new();
-        self.image.set_image(&Image::with_data(image_bytes));
+        self.image.set_image(&Image::with_data(image_bytes).unwrap());
         view.add_subview(&self.image);
 
         LayoutConstraint::activate(&[
@@ -0,0 +1,59 @@
+//! Types used for exposing views to assistive technologies (e.g, VoiceOver).
+//!
+//! Standard controls handle this for you. Custom-drawn views, however, are invisible to assistive
+//! technologies unless you describe them - see `View::set_accessibility_label()` and friends.
+
+use crate::foundation::NSString;
+
+/// Describes what kind of element a view represents. This maps to `NSAccessibilityRole`, and
+/// determines how assistive technologies present and interact with the view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// A button, which can be pressed.
+    Button,
+
+    /// A checkbox.
+    CheckBox,
+
+    /// An image.
+    Image,
+
+    /// A link, which navigates somewhere when pressed.
+    Link,
+
+    /// A list of items.
+    List,
+
+    /// A slider.
+    Slider,
+
+    /// A piece of static (non-editable) text.
+    StaticText,
+
+    /// A text field.
+    TextField,
+
+    /// A container for other elements.
+    Group,
+
+    /// A role not known to assistive technologies. This is what plain views default to.
+    Unknown
+}
+
+impl From<Role> for NSString<'_> {
+    fn from(role: Role) -> Self {
+        // The values of the corresponding `NSAccessibility*Role` constants.
+        NSString::new(match role {
+            Role::Button => "AXButton",
+            Role::CheckBox => "AXCheckBox",
+            Role::Image => "AXImage",
+            Role::Link => "AXLink",
+            Role::List => "AXList",
+            Role::Slider => "AXSlider",
+            Role::StaticText => "AXStaticText",
+            Role::TextField => "AXTextField",
+            Role::Group => "AXGroup",
+            Role::Unknown => "AXUnknown"
+        })
+    }
+}
@@ -24,38 +24,156 @@
 //! }
 //! ```
 
+use std::cell::RefCell;
+
+use block::ConcreteBlock;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
-use objc_id::Id;
+use objc_id::ShareId;
+
+use crate::appkit::window::Window;
+use crate::foundation::{id, NSInteger, NSString};
+
+/// Represents the button (or other outcome) that dismissed an `Alert`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AlertResponse {
+    /// A button was clicked. Holds the index of the button, in the order they were added - i.e,
+    /// `Button(0)` is the first (and default) button.
+    Button(usize),
+
+    /// The modal session was stopped.
+    Stopped,
+
+    /// The modal session was aborted.
+    Aborted,
+
+    /// The modal session should continue.
+    Continue,
+
+    /// A response code that we don't know how to map.
+    Unknown(NSInteger)
+}
 
-use crate::foundation::{id, NSString};
+impl From<NSInteger> for AlertResponse {
+    fn from(response: NSInteger) -> Self {
+        match response {
+            -1000 => AlertResponse::Stopped,
+            -1001 => AlertResponse::Aborted,
+            -1002 => AlertResponse::Continue,
+
+            // NSAlertFirstButtonReturn is 1000, and subsequent buttons count up from there.
+            i if i >= 1000 => AlertResponse::Button((i - 1000) as usize),
+
+            i => AlertResponse::Unknown(i)
+        }
+    }
+}
 
 /// Represents an `NSAlert`. Has no information other than the retained pointer to the Objective C
 /// side, so... don't bother inspecting this.
 #[derive(Debug)]
-pub struct Alert(Id<Object>);
+pub struct Alert(ShareId<Object>);
 
 impl Alert {
     /// Creates a basic `NSAlert`, storing a pointer to it in the Objective C runtime.
-    /// You can show this alert by calling `show()`.
+    ///
+    /// If you don't add any buttons via `add_button()`, the alert will display a single "OK"
+    /// button. You can show this alert by calling `show()`, `run_modal()`, or `begin_sheet()`.
     pub fn new(title: &str, message: &str) -> Self {
         let title = NSString::new(title);
         let message = NSString::new(message);
-        let ok = NSString::new("OK");
 
         Alert(unsafe {
             let alert: id = msg_send![class!(NSAlert), new];
             let _: () = msg_send![alert, setMessageText: title];
             let _: () = msg_send![alert, setInformativeText: message];
-            let _: () = msg_send![alert, addButtonWithTitle: ok];
-            Id::from_ptr(alert)
+            ShareId::from_retained_ptr(alert)
         })
     }
 
-    /// Shows this alert as a modal.
-    pub fn show(&self) {
+    /// Adds a button with the given title. Buttons are laid out right-to-left in the order
+    /// they're added, and the first one is the default.
+    ///
+    /// ```rust,no_run
+    /// use cacao::appkit::{Alert, AlertResponse};
+    ///
+    /// let alert = Alert::new("Delete file?", "This can't be undone.")
+    ///     .add_button("Delete")
+    ///     .add_button("Cancel");
+    ///
+    /// if alert.run_modal() == AlertResponse::Button(0) {
+    ///     // Delete the file...
+    /// }
+    /// ```
+    pub fn add_button(self, title: &str) -> Self {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: id = msg_send![&*self.0, addButtonWithTitle:&*title];
+        }
+
+        self
+    }
+
+    /// Shows this alert, calling `handler` with the response once it's dismissed.
+    ///
+    /// If a `window` is given, the alert runs as a sheet on it and this returns immediately (see
+    /// `begin_sheet()`). Otherwise, it runs as an application-wide modal, and `handler` is called
+    /// before this returns (see `run_modal()`).
+    ///
+    /// ```rust,no_run
+    /// use cacao::appkit::window::Window;
+    /// use cacao::appkit::{Alert, AlertResponse};
+    ///
+    /// # let window: Option<&Window> = None;
+    /// Alert::new("Delete file?", "This can't be undone.")
+    ///     .add_button("Delete")
+    ///     .add_button("Cancel")
+    ///     .show(window, |response| {
+    ///         if response == AlertResponse::Button(0) {
+    ///             // Delete the file...
+    ///         }
+    ///     });
+    /// ```
+    pub fn show<W, F>(self, window: Option<&Window<W>>, handler: F)
+    where
+        F: Fn(AlertResponse) + 'static
+    {
+        match window {
+            Some(window) => self.begin_sheet(window, handler),
+            None => handler(self.run_modal())
+        }
+    }
+
+    /// Shows this alert as an application-wide modal, blocking until it's dismissed, and returns
+    /// the response.
+    pub fn run_modal(&self) -> AlertResponse {
+        let response: NSInteger = unsafe { msg_send![&*self.0, runModal] };
+        response.into()
+    }
+
+    /// Shows this alert as a sheet on the given window. This returns immediately; `handler` is
+    /// called with the response once the sheet is dismissed.
+    ///
+    /// The underlying `NSAlert` is kept alive until the sheet has been dismissed, so it's fine to
+    /// drop this `Alert` after calling this.
+    pub fn begin_sheet<W, F>(&self, window: &Window<W>, handler: F)
+    where
+        F: Fn(AlertResponse) + 'static
+    {
+        let alert = RefCell::new(Some(self.0.clone()));
+
+        let block = ConcreteBlock::new(move |response: NSInteger| {
+            handler(response.into());
+
+            // The sheet is done, so we can let go of our hold on the alert.
+            alert.borrow_mut().take();
+        });
+
+        let block = block.copy();
+
         unsafe {
-            let _: () = msg_send![&*self.0, runModal];
+            let _: () = msg_send![&*self.0, beginSheetModalForWindow:&*window.objc completionHandler:&*block];
         }
     }
 }
@@ -1,6 +1,30 @@
 //! Various types used at the AppController level.
 
-use crate::foundation::NSUInteger;
+use crate::foundation::{NSInteger, NSUInteger};
+
+/// Determines whether (and how) an application shows up in the Dock and menu bar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActivationPolicy {
+    /// An ordinary application: it appears in the Dock, has a menu bar, and can be activated.
+    Regular,
+
+    /// The application doesn't appear in the Dock or have a menu bar, but can be activated (e.g,
+    /// by clicking one of its windows). This is common for menu bar extras.
+    Accessory,
+
+    /// The application doesn't appear in the Dock and can't create windows or be activated.
+    Prohibited
+}
+
+impl From<ActivationPolicy> for NSInteger {
+    fn from(policy: ActivationPolicy) -> Self {
+        match policy {
+            ActivationPolicy::Regular => 0,
+            ActivationPolicy::Accessory => 1,
+            ActivationPolicy::Prohibited => 2
+        }
+    }
+}
 
 /// Used for determining how an application should handle quitting/terminating.
 /// You return this in your `AppController` `should_terminate` method.
@@ -44,7 +44,8 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
 use crate::appkit::menu::Menu;
-use crate::foundation::{id, nil, AutoReleasePool, NSUInteger, NO, YES};
+use crate::bundle::Bundle;
+use crate::foundation::{id, nil, AutoReleasePool, NSInteger, NSUInteger, BOOL, NO, YES};
 use crate::invoker::TargetActionHandler;
 use crate::notification_center::Dispatcher;
 use crate::utils::activate_cocoa_multithreading;
@@ -147,6 +148,12 @@ where
             Id::from_ptr(app)
         };
 
+        // Without a bundle, there's no Info.plist telling the system that this is a regular app,
+        // and it would otherwise launch without a Dock icon or menu bar.
+        if Bundle::main().identifier().is_none() {
+            App::set_activation_policy(ActivationPolicy::Regular);
+        }
+
         let app_delegate = Box::new(delegate);
 
         let objc_delegate = unsafe {
@@ -285,17 +292,32 @@ impl App {
         });
     }
 
+    /// Sets the activation policy for the application, which determines whether it shows up in the
+    /// Dock and has a menu bar.
+    ///
+    /// Binaries that aren't running from an `.app` bundle (e.g, via `cargo run`) have no
+    /// `Info.plist` to specify this, so `App::new()` sets `ActivationPolicy::Regular` for them.
+    pub fn set_activation_policy(policy: ActivationPolicy) {
+        let policy: NSInteger = policy.into();
+
+        shared_application(|app| unsafe {
+            let _: BOOL = msg_send![app, setActivationPolicy: policy];
+        });
+    }
+
     /// For nib-less applications (which, if you're here, this is) need to call the activation
     /// routines after the NSMenu has been set, otherwise it won't be interact-able without
     /// switching away from the app and then coming back.
     ///
-    /// @TODO: Accept an ActivationPolicy enum or something.
+    /// This sets `ActivationPolicy::Regular`; if you need something else, call
+    /// `set_activation_policy()` afterwards.
     pub fn activate() {
-        shared_application(|app| unsafe {
-            let _: () = msg_send![app, setActivationPolicy:0];
+        App::set_activation_policy(ActivationPolicy::Regular);
+
+        unsafe {
             let current_app: id = msg_send![class!(NSRunningApplication), currentApplication];
             let _: () = msg_send![current_app, activateWithOptions:1<<1];
-        });
+        }
     }
 
     /// Terminates the application, firing the requisite cleanup delegate methods in the process.
@@ -4,7 +4,7 @@ use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
-use crate::foundation::{id, nil, NSString};
+use crate::foundation::{id, nil, to_bool, NSString, BOOL};
 
 /// An EventMask describes the type of event.
 #[derive(Debug)]
@@ -76,6 +76,86 @@ impl Event {
 
 use crate::foundation::NSUInteger;
 
+/// Represents the phase of a scroll (or other gesture) event. Devices with precise scrolling
+/// deltas (e.g, trackpads, Magic Mouse) report phases; traditional scroll wheels report `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventPhase {
+    /// No phase information is available (e.g, a traditional scroll wheel, or a momentum event).
+    None,
+
+    /// The gesture has begun.
+    Began,
+
+    /// The gesture is in progress, but hasn't moved.
+    Stationary,
+
+    /// The gesture has moved.
+    Changed,
+
+    /// The gesture has ended.
+    Ended,
+
+    /// The system cancelled the gesture.
+    Cancelled,
+
+    /// The gesture may begin (e.g, fingers have touched the trackpad, but not yet moved).
+    MayBegin
+}
+
+impl From<NSUInteger> for EventPhase {
+    fn from(phase: NSUInteger) -> Self {
+        match phase {
+            1 => EventPhase::Began,
+            2 => EventPhase::Stationary,
+            4 => EventPhase::Changed,
+            8 => EventPhase::Ended,
+            16 => EventPhase::Cancelled,
+            32 => EventPhase::MayBegin,
+            _ => EventPhase::None
+        }
+    }
+}
+
+/// The details of a scroll wheel (or trackpad scroll) event, pulled out of the underlying `NSEvent`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollEvent {
+    /// The horizontal scroll amount.
+    pub delta_x: f64,
+
+    /// The vertical scroll amount.
+    pub delta_y: f64,
+
+    /// If `true`, the deltas are precise pixel values (e.g, from a trackpad). If `false`, they're
+    /// line-based values from a traditional scroll wheel, and you'll likely want to scale them.
+    pub has_precise_scrolling_deltas: bool,
+
+    /// The phase of the scroll gesture.
+    pub phase: EventPhase,
+
+    /// The momentum phase, for the scrolling that continues after the user lifts their fingers.
+    /// This is `EventPhase::None` while the user is scrolling directly.
+    pub momentum_phase: EventPhase
+}
+
+impl ScrollEvent {
+    /// Extracts scroll information from the given `NSEvent`.
+    pub(crate) fn new(event: id) -> Self {
+        unsafe {
+            let has_precise_scrolling_deltas: BOOL = msg_send![event, hasPreciseScrollingDeltas];
+            let phase: NSUInteger = msg_send![event, phase];
+            let momentum_phase: NSUInteger = msg_send![event, momentumPhase];
+
+            ScrollEvent {
+                delta_x: msg_send![event, scrollingDeltaX],
+                delta_y: msg_send![event, scrollingDeltaY],
+                has_precise_scrolling_deltas: to_bool(has_precise_scrolling_deltas),
+                phase: phase.into(),
+                momentum_phase: momentum_phase.into()
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum EventModifierFlag {
     CapsLock,
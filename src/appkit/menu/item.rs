@@ -12,7 +12,7 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
 use crate::events::EventModifierFlag;
-use crate::foundation::{id, nil, NSString, NSUInteger};
+use crate::foundation::{id, nil, DelegateContainer, NSString, NSUInteger};
 
 static BLOCK_PTR: &'static str = "cacaoMenuItemBlockPtr";
 
@@ -267,11 +267,8 @@ impl MenuItem {
     /// whenever the menu item goes kaput.
     pub fn action<F: Fn() + 'static>(self, action: F) -> Self {
         if let MenuItem::Custom(mut objc) = self {
-            let handler = Box::new(Action(Box::new(action)));
-            let ptr = Box::into_raw(handler);
-
             unsafe {
-                (&mut *objc).set_ivar(BLOCK_PTR, ptr as usize);
+                DelegateContainer::set(&mut *objc, BLOCK_PTR, Action(Box::new(action)));
                 let _: () = msg_send![&*objc, setTarget:&*objc];
             }
 
@@ -285,14 +282,9 @@ impl MenuItem {
 /// On the Objective-C side, we need to ensure our handler is dropped when this subclass
 /// is deallocated. Note that NSMenuItem is seemingly odd outside of ARC contexts, and we
 /// need to do some extra logic to ensure release calls are properly sent.
-extern "C" fn dealloc_cacao_menuitem(this: &Object, _: Sel) {
+extern "C" fn dealloc_cacao_menuitem(this: &mut Object, _: Sel) {
     unsafe {
-        let ptr: usize = *this.get_ivar(BLOCK_PTR);
-        let obj = ptr as *mut Action;
-
-        if !obj.is_null() {
-            let _handler = Box::from_raw(obj);
-        }
+        DelegateContainer::<Action>::clear(this, BLOCK_PTR);
 
         // This should be fine to _not_ do, but considering we go out of our way to loop it back on
         // itself, it's worth clearing out the slot.
@@ -304,8 +296,9 @@ extern "C" fn dealloc_cacao_menuitem(this: &Object, _: Sel) {
 
 /// Called when our custom item needs to fire.
 extern "C" fn fire_block_action(this: &Object, _: Sel, _item: id) {
-    let action = crate::utils::load::<Action>(this, BLOCK_PTR);
-    (action.0)();
+    if let Some(action) = unsafe { DelegateContainer::<Action>::get(this, BLOCK_PTR) } {
+        (action.0)();
+    }
 }
 
 /// Injects a custom NSMenuItem subclass that contains a slot to hold a block, as well as a method
@@ -322,7 +315,7 @@ pub(crate) fn register_menu_item_class() -> *const Class {
         let mut decl = ClassDecl::new("CacaoMenuItem", superclass).unwrap();
         decl.add_ivar::<usize>(BLOCK_PTR);
 
-        decl.add_method(sel!(dealloc), dealloc_cacao_menuitem as extern "C" fn(&Object, _));
+        decl.add_method(sel!(dealloc), dealloc_cacao_menuitem as extern "C" fn(&mut Object, _));
         decl.add_method(sel!(fireBlockAction:), fire_block_action as extern "C" fn(&Object, _, id));
 
         APP_CLASS = decl.register();
@@ -6,7 +6,7 @@
 //! applications. For examples, check the `examples` folder in the repository.
 
 mod alert;
-pub use alert::Alert;
+pub use alert::{Alert, AlertResponse};
 
 mod animation;
 pub use animation::AnimationContext;
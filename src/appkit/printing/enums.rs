@@ -30,3 +30,22 @@ impl From<PrintResponse> for NSUInteger {
         }
     }
 }
+
+/// The orientation of printed pages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PrintOrientation {
+    /// Pages are taller than they are wide.
+    Portrait,
+
+    /// Pages are wider than they are tall.
+    Landscape
+}
+
+impl From<PrintOrientation> for NSUInteger {
+    fn from(orientation: PrintOrientation) -> NSUInteger {
+        match orientation {
+            PrintOrientation::Portrait => 0,
+            PrintOrientation::Landscape => 1
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //! itself).
 
 pub mod enums;
-pub use enums::PrintResponse;
+pub use enums::{PrintOrientation, PrintResponse};
 
 pub mod settings;
 pub use settings::PrintSettings;
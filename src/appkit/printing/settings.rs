@@ -2,17 +2,29 @@
 //! aims to make it easier to query/process printing operations.
 
 use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
-use crate::foundation::id;
+use crate::appkit::printing::enums::PrintOrientation;
+use crate::foundation::{id, NSNumber, NSString, NSUInteger};
 
 /// `PrintSettings` represents options used in printing, typically passed to you by the
 /// application/user.
+///
+/// You can also create your own (e.g, for `WebView::print_with_settings()`); these start out as a
+/// copy of the application's shared print settings.
 #[derive(Clone, Debug)]
 pub struct PrintSettings {
     pub inner: ShareId<Object>
 }
 
+impl Default for PrintSettings {
+    /// Returns a copy of the application's shared print settings.
+    fn default() -> Self {
+        PrintSettings::new()
+    }
+}
+
 impl PrintSettings {
     /// Internal method, constructs a wrapper around the backing `NSDictionary` print settings.
     pub(crate) fn with_inner(inner: id) -> Self {
@@ -20,4 +32,50 @@ impl PrintSettings {
             inner: unsafe { ShareId::from_ptr(inner) }
         }
     }
+
+    /// Creates a new set of print settings, copied from the application's shared print settings.
+    pub fn new() -> Self {
+        PrintSettings {
+            inner: unsafe {
+                let shared: id = msg_send![class!(NSPrintInfo), sharedPrintInfo];
+                let dictionary: id = msg_send![shared, dictionary];
+                ShareId::from_retained_ptr(msg_send![dictionary, copy])
+            }
+        }
+    }
+
+    /// Sets the page orientation.
+    pub fn set_orientation(&mut self, orientation: PrintOrientation) {
+        let value: NSUInteger = orientation.into();
+        self.set_number("NSOrientation", NSNumber::integer(value as i64));
+    }
+
+    /// Sets the page margins, in points.
+    pub fn set_margins(&mut self, top: f64, left: f64, bottom: f64, right: f64) {
+        self.set_number("NSTopMargin", NSNumber::float(top));
+        self.set_number("NSLeftMargin", NSNumber::float(left));
+        self.set_number("NSBottomMargin", NSNumber::float(bottom));
+        self.set_number("NSRightMargin", NSNumber::float(right));
+    }
+
+    /// Sets a numeric value for one of the `NSPrintInfo` attribute keys. Settings passed to us by
+    /// the system are immutable (and clones share the same dictionary), so we write into a fresh
+    /// mutable copy and swap it in.
+    fn set_number(&mut self, key: &str, value: NSNumber) {
+        let key = NSString::new(key);
+
+        self.inner = unsafe {
+            let copy: id = msg_send![&*self.inner, mutableCopy];
+            let _: () = msg_send![copy, setObject:&*value.0 forKey:&*key];
+            ShareId::from_retained_ptr(copy)
+        };
+    }
+
+    /// Returns a new (retained) `NSPrintInfo` configured with these settings.
+    pub(crate) fn to_print_info(&self) -> id {
+        unsafe {
+            let alloc: id = msg_send![class!(NSPrintInfo), alloc];
+            msg_send![alloc, initWithDictionary:&*self.inner]
+        }
+    }
 }
@@ -20,6 +20,7 @@ use objc_id::ShareId;
 use crate::appkit::toolbar::{Toolbar, ToolbarDelegate};
 use crate::color::Color;
 use crate::foundation::{id, nil, to_bool, NSInteger, NSString, NSUInteger, NO, YES};
+use crate::geometry::Rect;
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::{os, Controller};
@@ -233,6 +234,25 @@ impl<T> Window<T> {
         }
     }
 
+    /// Sets the frame (position and size, including the title bar) of this window, and redraws it.
+    ///
+    /// Note that window frames are in screen coordinates, where the origin is the bottom-left of
+    /// the main screen - so `top` here is the distance of the window's bottom edge from the
+    /// bottom of the screen.
+    pub fn set_frame(&self, rect: Rect) {
+        let frame: CGRect = rect.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFrame:frame display:YES];
+        }
+    }
+
+    /// Returns the frame of this window, in screen coordinates. See `set_frame()`.
+    pub fn frame(&self) -> Rect {
+        let frame: CGRect = unsafe { msg_send![&*self.objc, frame] };
+        frame.into()
+    }
+
     /// Sets the content size for this window.
     pub fn set_content_size<F: Into<f64>>(&self, width: F, height: F) {
         unsafe {
@@ -292,7 +312,14 @@ impl<T> Window<T> {
     }
 
     /// Given a view, sets it as the content view for this window.
+    ///
+    /// The window sizes its content view directly, so the view is switched back to translating
+    /// its autoresizing mask into constraints. Autolayout still works as expected for subviews
+    /// anchored against it.
     pub fn set_content_view<L: Layout + 'static>(&self, view: &L) {
+        #[cfg(feature = "autolayout")]
+        view.set_translates_autoresizing_mask_into_constraints(true);
+
         view.with_backing_obj_mut(|backing_node| unsafe {
             let _: () = msg_send![&*self.objc, setContentView:&*backing_node];
         });
@@ -314,6 +341,14 @@ impl<T> Window<T> {
         }
     }
 
+    /// Centers the window on the screen. Per AppKit, this is placed slightly above the true
+    /// center, which tends to look better.
+    pub fn center(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, center];
+        }
+    }
+
     /// On macOS, calling `close()` is equivalent to calling... well, `close`. It closes the
     /// window.
     ///
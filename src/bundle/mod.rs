@@ -0,0 +1,98 @@
+//! A wrapper for `NSBundle`, which is mostly useful for resolving paths to resources (e.g, web
+//! content or images) that ship inside your application bundle, and reading values out of your
+//! `Info.plist`.
+//!
+//! ```rust,no_run
+//! use cacao::bundle::Bundle;
+//!
+//! let bundle = Bundle::main();
+//! let index = bundle.path_for_resource("index", Some("html"));
+//! let version = bundle.version();
+//! ```
+
+use std::path::PathBuf;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSString};
+
+// Swizzles `bundleIdentifier` for cases where you're running outside of a bundle. Not currently
+// in use; see the notes in the module.
+//mod swizzle;
+
+/// A wrapper around `NSBundle`.
+#[derive(Clone, Debug)]
+pub struct Bundle(pub ShareId<Object>);
+
+impl Bundle {
+    /// Returns the bundle for the currently running application.
+    ///
+    /// Note that this will still return a bundle when running outside of an `.app` (e.g, via
+    /// `cargo run`), but it will likely be missing an identifier and most resources.
+    pub fn main() -> Self {
+        Bundle(unsafe { ShareId::from_ptr(msg_send![class!(NSBundle), mainBundle]) })
+    }
+
+    /// Returns the bundle with the given identifier, if it's been loaded (or can be found).
+    pub fn with_identifier(identifier: &str) -> Option<Self> {
+        let identifier = NSString::new(identifier);
+
+        let bundle: id = unsafe { msg_send![class!(NSBundle), bundleWithIdentifier:&*identifier] };
+
+        match bundle.is_null() {
+            true => None,
+            false => Some(Bundle(unsafe { ShareId::from_ptr(bundle) }))
+        }
+    }
+
+    /// Returns the bundle identifier (e.g, `com.example.app`), if one is set.
+    pub fn identifier(&self) -> Option<String> {
+        NSString::to_string_opt(unsafe { msg_send![&*self.0, bundleIdentifier] })
+    }
+
+    /// Returns the path to the given resource in this bundle, or `None` if it can't be found.
+    /// `extension` can be omitted if `name` already includes it.
+    pub fn path_for_resource(&self, name: &str, extension: Option<&str>) -> Option<PathBuf> {
+        let name = NSString::new(name);
+        let extension = extension.map(NSString::new);
+
+        let path: id = unsafe {
+            match &extension {
+                Some(ext) => msg_send![&*self.0, pathForResource:&*name ofType:&**ext],
+                None => msg_send![&*self.0, pathForResource:&*name ofType:nil]
+            }
+        };
+
+        NSString::to_string_opt(path).map(PathBuf::from)
+    }
+
+    /// Returns the path to the bundle's resources directory, if it has one.
+    pub fn resource_path(&self) -> Option<PathBuf> {
+        NSString::to_string_opt(unsafe { msg_send![&*self.0, resourcePath] }).map(PathBuf::from)
+    }
+
+    /// Returns the value for `key` in this bundle's `Info.plist` (localized, if applicable), if
+    /// it exists and is a string.
+    pub fn info_dictionary_value(&self, key: &str) -> Option<String> {
+        let key = NSString::new(key);
+
+        let value: id = unsafe { msg_send![&*self.0, objectForInfoDictionaryKey:&*key] };
+
+        match !value.is_null() && NSString::is(value) {
+            true => Some(NSString::retain(value).to_string()),
+            false => None
+        }
+    }
+
+    /// Returns the user-facing version of this bundle (`CFBundleShortVersionString`), if set.
+    pub fn version(&self) -> Option<String> {
+        self.info_dictionary_value("CFBundleShortVersionString")
+    }
+
+    /// Returns the build number of this bundle (`CFBundleVersion`), if set.
+    pub fn build_version(&self) -> Option<String> {
+        self.info_dictionary_value("CFBundleVersion")
+    }
+}
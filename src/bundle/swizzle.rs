@@ -20,6 +20,7 @@ use objc::runtime::{
     method_exchangeImplementations
 };
 
+use crate::bundle::Bundle;
 use crate::foundation::{id, nil, BOOL, YES, NSString};
 
 /// Types that can be used as the implementation of an Objective-C method.
@@ -59,13 +60,9 @@ method_decl_impl!(A);
 
 extern "C" fn get_bundle_id(this: &Object, s: Sel, v: id) -> id {
     unsafe {
-        let bundle = class!(NSBundle);
-        let main_bundle: id = msg_send![bundle, mainBundle];
-        let e: BOOL = msg_send![this, isEqual:main_bundle];
+        let main_bundle = Bundle::main();
+        let e: BOOL = msg_send![this, isEqual:&*main_bundle.0];
         if e == YES {
-            let url: id = msg_send![main_bundle, bundleURL];
-            let x: id = msg_send![url, absoluteString];
-            println!("Got here? {:?}", x);
             NSString::new("com.test.user_notifications").into()
         } else {
             msg_send![this, __bundleIdentifier]
@@ -183,6 +183,15 @@ impl Button {
         }
     }
 
+    /// Sets the title displayed on this button.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTitle:&*title];
+        });
+    }
+
     /// Sets an image on the underlying button.
     pub fn set_image(&mut self, image: Image) {
         self.objc.with_mut(|obj| unsafe {
@@ -204,7 +213,10 @@ impl Button {
 
     /// Attaches a callback for button press events. Don't get too creative now...
     /// best just to message pass or something.
-    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+    ///
+    /// The callback is held for as long as this `Button` is alive, and is released on drop (or
+    /// when another action replaces it).
+    pub fn set_action<F: Fn() + 'static>(&mut self, action: F) {
         // @TODO: This probably isn't ideal but gets the job done for now; needs revisiting.
         let this = self.objc.get(|obj| unsafe { ShareId::from_ptr(msg_send![obj, self]) });
         let handler = TargetActionHandler::new(&*this, action);
@@ -337,6 +349,8 @@ impl Drop for Button {
             let _: () = msg_send![obj, setTarget: nil];
             let _: () = msg_send![obj, setAction: nil];
         });
+
+        self.handler = None;
     }
 }
 
@@ -22,6 +22,7 @@ use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
+use crate::error::Error;
 use crate::foundation::id;
 use crate::utils::os;
 
@@ -239,22 +240,53 @@ pub enum Color {
 }
 
 impl Color {
-    /// Creates and returns a color in the RGB space, with the specified
+    /// Creates and returns a color in the sRGB space, with the specified
     /// alpha level.
+    ///
+    /// sRGB is what the web (and most design specs) assume, so this is the right choice for the
+    /// vast majority of cases - e.g, matching a brand color, or content in a `WebView`. If you're
+    /// doing precise color work on wide-gamut displays, see `Color::p3()`.
     pub fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Color::rgba_float(
+            red as CGFloat / 255.0,
+            green as CGFloat / 255.0,
+            blue as CGFloat / 255.0,
+            alpha as CGFloat / 255.0
+        )
+    }
+
+    /// Creates and returns a color in the sRGB space from floating point components, each in the
+    /// range `0.0` to `1.0` - e.g, for values coming from a design tool or a color picker. See
+    /// `Color::rgba()`.
+    pub fn rgba_float(r: CGFloat, g: CGFloat, b: CGFloat, a: CGFloat) -> Self {
+        #[cfg(feature = "appkit")]
+        let ptr = unsafe { Id::from_ptr(msg_send![class!(NSColor), colorWithSRGBRed:r green:g blue:b alpha:a]) };
+        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+        let ptr = unsafe { Id::from_ptr(msg_send![class!(UIColor), colorWithRed:r green:g blue:b alpha:a]) };
+
+        Color::Custom(Arc::new(RwLock::new(ptr)))
+    }
+
+    /// Creates and returns a color in the Display P3 space, with the specified alpha level.
+    ///
+    /// Display P3 covers a wider gamut than sRGB, and is what most modern Apple displays use.
+    /// Reach for this when you need colors that sRGB can't represent, or when your values come
+    /// from a P3-aware tool (e.g, a design app set to Display P3) - the same component values in
+    /// `Color::rgba()` would render differently.
+    pub fn p3(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
         let r = red as CGFloat / 255.0;
         let g = green as CGFloat / 255.0;
         let b = blue as CGFloat / 255.0;
         let a = alpha as CGFloat / 255.0;
         #[cfg(feature = "appkit")]
-        let ptr = unsafe { Id::from_ptr(msg_send![class!(NSColor), colorWithCalibratedRed:r green:g blue:b alpha:a]) };
+        let ptr = unsafe { Id::from_ptr(msg_send![class!(NSColor), colorWithDisplayP3Red:r green:g blue:b alpha:a]) };
         #[cfg(all(feature = "uikit", not(feature = "appkit")))]
-        let ptr = unsafe { Id::from_ptr(msg_send![class!(UIColor), colorWithRed:r green:g blue:b alpha:a]) };
+        let ptr = unsafe { Id::from_ptr(msg_send![class!(UIColor), colorWithDisplayP3Red:r green:g blue:b alpha:a]) };
 
         Color::Custom(Arc::new(RwLock::new(ptr)))
     }
 
-    /// Creates and returns a color in the RGB space, with the alpha level
+    /// Creates and returns a color in the sRGB space, with the alpha level
     /// set to `255` by default. Shorthand for `rgba`.
     pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
         Color::rgba(red, green, blue, 255)
@@ -309,22 +341,51 @@ impl Color {
         Color::white_alpha(level, 1.0)
     }
 
-    /// Given a hex code and alpha level, returns a `Color` in the RGB space.
+    /// Parses a hex code (e.g, `#ff8800`) into a `Color` in the sRGB space. The leading `#` is
+    /// optional, and the `RGB`, `RRGGBB` and `RRGGBBAA` forms are supported.
+    ///
+    /// Returns `Error::InvalidHexColor` if `hex` can't be parsed.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let (r, g, b, a) = parse_hex(hex)?;
+        Ok(Color::rgba(r, g, b, a.unwrap_or(255)))
+    }
+
+    /// Given a hex code and alpha level, returns a `Color` in the sRGB space. Any alpha component
+    /// in `hex` is ignored in favor of `alpha`.
     ///
     /// This method is not an ideal one to use, but is offered as a convenience method for those
-    /// coming from other environments where these are more common.
-    pub fn hexa(_hex: &str, _alpha: u8) -> Self {
-        Color::SystemRed
+    /// coming from other environments where these are more common. It returns
+    /// `Color::SystemRed` if `hex` can't be parsed; use `from_hex()` to handle that yourself.
+    pub fn hexa(hex: &str, alpha: u8) -> Self {
+        match parse_hex(hex) {
+            Ok((r, g, b, _)) => Color::rgba(r, g, b, alpha),
+            Err(_) => Color::SystemRed
+        }
     }
 
-    /// Given a hex code, returns a `Color` in the RGB space with alpha pre-set to `255`.
+    /// Given a hex code, returns a `Color` in the sRGB space with alpha pre-set to `255`.
     ///
     /// This method is not an ideal one to use, but is offered as a convenience method for those
-    /// coming from other environments where these are more common.
+    /// coming from other environments where these are more common. It returns
+    /// `Color::SystemRed` if `hex` can't be parsed; use `from_hex()` to handle that yourself.
     pub fn hex(hex: &str) -> Self {
         Color::hexa(hex, 255)
     }
 
+    /// Creates and returns a dynamic color that uses `light` in light mode, and `dark` in dark
+    /// mode, regardless of contrast settings. Shorthand for `dynamic()`, which you can use if you
+    /// need finer control (it takes a closure, hence the separate name for this two-color form).
+    ///
+    /// For named system colors, which already adapt, just use the matching variant (e.g,
+    /// `Color::SystemBlue` or `Color::Label`).
+    #[cfg(feature = "appkit")]
+    pub fn light_dark(light: Color, dark: Color) -> Self {
+        Color::dynamic(move |style| match style.theme {
+            Theme::Dark => dark.clone(),
+            Theme::Light => light.clone()
+        })
+    }
+
     // @TODO: This is currently appkit-only but should be for uikit as well.
     /// Creates and returns a dynamic color, which stores a handler and enables returning specific
     /// colors at appearance time based on device traits (i.e, dark mode vs light mode, contrast
@@ -406,6 +467,35 @@ impl Color {
     }
 }
 
+/// Parses a `#RGB`, `#RRGGBB` or `#RRGGBBAA` hex code (with the `#` being optional) into its
+/// components. The alpha component is only present in the last form.
+fn parse_hex(hex: &str) -> Result<(u8, u8, u8, Option<u8>), Error> {
+    let invalid = || Error::InvalidHexColor(hex.to_string());
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
+    let channel = |index: usize, len: usize| -> Result<u8, Error> {
+        let start = index * len;
+        let value = u8::from_str_radix(&digits[start..start + len], 16).map_err(|_| invalid())?;
+
+        // Shorthand (`#f80`) channels are doubled up, i.e `f` => `ff`.
+        Ok(match len {
+            1 => value * 17,
+            _ => value
+        })
+    };
+
+    match digits.len() {
+        3 => Ok((channel(0, 1)?, channel(1, 1)?, channel(2, 1)?, None)),
+        6 => Ok((channel(0, 2)?, channel(1, 2)?, channel(2, 2)?, None)),
+        8 => Ok((channel(0, 2)?, channel(1, 2)?, channel(2, 2)?, Some(channel(3, 2)?))),
+        _ => Err(invalid())
+    }
+}
+
 impl AsRef<Color> for Color {
     /// Provided to make passing `Color` types around less of a headache.
     #[inline]
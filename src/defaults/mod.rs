@@ -220,6 +220,123 @@ impl UserDefaults {
         None
     }
 
+    /// Stores a string for the specified key. Shorthand for `insert(key, Value::string(value))`.
+    ///
+    /// ```rust
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_string("test", "value");
+    /// ```
+    pub fn set_string<K: AsRef<str>, S: Into<String>>(&mut self, key: K, value: S) {
+        self.insert(key, Value::string(value));
+    }
+
+    /// Returns the string stored for the specified key, if there is one. Non-string values are
+    /// treated as missing.
+    ///
+    /// ```rust,no_run
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_string("test", "value");
+    ///
+    /// assert_eq!(defaults.string("test"), Some("value".to_string()));
+    /// ```
+    pub fn string<K: AsRef<str>>(&self, key: K) -> Option<String> {
+        match self.get(key) {
+            Some(Value::String(s)) => Some(s),
+            _ => None
+        }
+    }
+
+    /// Stores a boolean for the specified key.
+    ///
+    /// ```rust
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_bool("test", true);
+    /// ```
+    pub fn set_bool<K: AsRef<str>>(&mut self, key: K, value: bool) {
+        self.insert(key, Value::Bool(value));
+    }
+
+    /// Returns the boolean stored for the specified key. Mirroring `boolForKey:`, this returns
+    /// `false` if no value exists for the key.
+    ///
+    /// ```rust,no_run
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_bool("test", true);
+    ///
+    /// assert_eq!(defaults.bool("test"), true);
+    /// ```
+    pub fn bool<K: AsRef<str>>(&self, key: K) -> bool {
+        let result: BOOL = unsafe {
+            let key = NSString::new(key.as_ref());
+            msg_send![&*self.0, boolForKey:&*key]
+        };
+
+        to_bool(result)
+    }
+
+    /// Stores an integer for the specified key.
+    ///
+    /// ```rust
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_i64("test", 42);
+    /// ```
+    pub fn set_i64<K: AsRef<str>>(&mut self, key: K, value: i64) {
+        self.insert(key, Value::Integer(value));
+    }
+
+    /// Returns the integer stored for the specified key, if there is one.
+    ///
+    /// ```rust,no_run
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_i64("test", 42);
+    ///
+    /// assert_eq!(defaults.i64("test"), Some(42));
+    /// ```
+    pub fn i64<K: AsRef<str>>(&self, key: K) -> Option<i64> {
+        self.get(key).and_then(|value| value.as_i64())
+    }
+
+    /// Stores raw bytes for the specified key. These are stored internally as `NSData`.
+    ///
+    /// ```rust
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_data("test", vec![1, 2, 3]);
+    /// ```
+    pub fn set_data<K: AsRef<str>>(&mut self, key: K, value: Vec<u8>) {
+        self.insert(key, Value::Data(value));
+    }
+
+    /// Returns the bytes stored for the specified key, if there are any.
+    ///
+    /// ```rust,no_run
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let mut defaults = UserDefaults::standard();
+    /// defaults.set_data("test", vec![1, 2, 3]);
+    ///
+    /// assert_eq!(defaults.data("test"), Some(vec![1, 2, 3]));
+    /// ```
+    pub fn data<K: AsRef<str>>(&self, key: K) -> Option<Vec<u8>> {
+        match self.get(key) {
+            Some(Value::Data(data)) => Some(data),
+            _ => None
+        }
+    }
+
     /// Returns a boolean value if the object stored for the specified key is managed by an
     /// administrator. This is rarely used - mostly in managed environments, e.g a classroom.
     ///
@@ -0,0 +1,54 @@
+//! Helpers for hopping work over to the main thread via Grand Central Dispatch.
+//!
+//! AppKit and UIKit expect nearly all UI work to happen on the main thread. If you're doing work
+//! on a background thread (networking, file processing, and so on), you can use these to loop
+//! the result back around.
+//!
+//! ```rust,no_run
+//! use std::thread;
+//! use cacao::dispatch;
+//!
+//! thread::spawn(|| {
+//!     let result = 1 + 1;
+//!
+//!     dispatch::on_main(move || {
+//!         println!("Back on the main thread with {}", result);
+//!     });
+//! });
+//! ```
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{to_bool, BOOL};
+
+/// Returns whether the calling thread is the main thread.
+pub fn is_main_thread() -> bool {
+    let result: BOOL = unsafe { msg_send![class!(NSThread), isMainThread] };
+    to_bool(result)
+}
+
+/// Asynchronously executes `method` on the main thread. This returns immediately; the closure is
+/// run on a future pass of the main run loop, and dropped afterwards.
+pub fn on_main<F>(method: F)
+where
+    F: FnOnce() + Send + 'static
+{
+    ::dispatch::Queue::main().exec_async(method);
+}
+
+/// Synchronously executes `method` on the main thread, blocking the calling thread until it has
+/// finished, and returns the result.
+///
+/// If this is called from the main thread, `method` is simply run in place - dispatching
+/// synchronously to the queue you're already on would otherwise deadlock.
+pub fn on_main_sync<F, T>(method: F) -> T
+where
+    F: FnOnce() -> T + Send,
+    T: Send
+{
+    if is_main_thread() {
+        return method();
+    }
+
+    ::dispatch::Queue::main().exec_sync(method)
+}
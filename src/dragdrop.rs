@@ -9,6 +9,21 @@ use objc_id::ShareId;
 use crate::foundation::NSUInteger;
 use crate::pasteboard::Pasteboard;
 
+#[cfg(feature = "appkit")]
+use core_graphics::geometry::{CGPoint, CGRect};
+
+#[cfg(feature = "appkit")]
+use objc::class;
+
+#[cfg(feature = "appkit")]
+use crate::foundation::{id, NSString};
+
+#[cfg(feature = "appkit")]
+use crate::geometry::{Point, Rect};
+
+#[cfg(feature = "appkit")]
+use crate::image::Image;
+
 /// Represents operations that can happen for a given drag/drop scenario.
 #[derive(Copy, Clone, Debug)]
 pub enum DragOperation {
@@ -65,4 +80,61 @@ impl DragInfo {
     pub fn get_pasteboard(&self) -> Pasteboard {
         unsafe { Pasteboard::with(msg_send![&*self.info, draggingPasteboard]) }
     }
+
+    /// Returns the current location of the drag, in the destination window's coordinate space.
+    /// To get it in a view's coordinate space, see `Layout::convert_point_from_window()`.
+    #[cfg(feature = "appkit")]
+    pub fn location(&self) -> Point {
+        let location: CGPoint = unsafe { msg_send![&*self.info, draggingLocation] };
+        location.into()
+    }
+}
+
+/// A wrapper for `NSDraggingItem`, which represents a single item in a drag that a view initiates
+/// (see `View::begin_dragging_session()`).
+///
+/// Each item can have its own drag image, which AppKit will stack when dragging multiple items.
+#[cfg(feature = "appkit")]
+#[derive(Clone, Debug)]
+pub struct DragItem {
+    pub objc: ShareId<Object>
+}
+
+#[cfg(feature = "appkit")]
+impl DragItem {
+    /// Creates a new drag item for the given pasteboard writer - i.e, any object that conforms to
+    /// `NSPasteboardWriting`, such as an `NSString`, `NSURL`, or `NSPasteboardItem`.
+    pub fn new(writer: id) -> Self {
+        DragItem {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(NSDraggingItem), alloc];
+                ShareId::from_retained_ptr(msg_send![alloc, initWithPasteboardWriter: writer])
+            }
+        }
+    }
+
+    /// Creates a new drag item that carries the given text.
+    pub fn with_string(text: &str) -> Self {
+        let text = NSString::new(text);
+
+        DragItem {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(NSDraggingItem), alloc];
+                ShareId::from_retained_ptr(msg_send![alloc, initWithPasteboardWriter:&*text])
+            }
+        }
+    }
+
+    /// Sets the image shown for this item while dragging. `frame` is in the coordinate space of
+    /// the view that begins the drag; a typical choice is the bounds of the element being dragged,
+    /// so the image starts out directly on top of it.
+    ///
+    /// If you don't set an image, nothing useful will be shown during the drag.
+    pub fn set_drag_image(&self, image: &Image, frame: Rect) {
+        let frame: CGRect = frame.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDraggingFrame:frame contents:&*image.0];
+        }
+    }
 }
@@ -1,4 +1,4 @@
-//! A wrapper for `NSError`.
+//! The error type used throughout the framework, including a wrapper for `NSError`.
 //!
 //! It attempts to be thread safe where possible, and extract the "default" usable information out of
 //! an `NSError`. This might not be what you need, though, so if it's missing something... well,
@@ -9,21 +9,40 @@ use std::fmt;
 
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, NSInteger, NSString};
+use crate::foundation::{id, NSError, NSInteger, NSMutableDictionary, NSString};
 
-/// A wrapper around pieces of data extracted from `NSError`. This could be improved: right now, it
-/// allocates `String` instances when theoretically it could be avoided, and we might be erasing
-/// certain parts of the `NSError` object that are useful.
+/// The domain used when converting one of our own (non-`NSError`) errors into an `NSError`.
+const CACAO_ERROR_DOMAIN: &str = "com.cacao-rs.error";
+
+/// Errors that can occur when working with the framework.
 #[derive(Clone, Debug)]
-pub struct Error {
-    /// Represents the code. Some of these can be... archaic.
-    pub code: usize,
+pub enum Error {
+    /// The Objective-C side returned `nil` where an object was expected - e.g, data that couldn't
+    /// be decoded into an image.
+    NilPointer,
+
+    /// A string wasn't validly encoded; this covers invalid UTF-8 as well as invalid UTF-16.
+    InvalidUtf8,
+
+    /// The given string couldn't be parsed as a URL.
+    InvalidUrl(String),
+
+    /// The given string couldn't be parsed as a hex color.
+    InvalidHexColor(String),
 
-    /// Represents the domain of the error.
-    pub domain: String,
+    /// An error bridged from an `NSError`. This could be improved: right now, it allocates
+    /// `String` instances when theoretically it could be avoided, and we might be erasing certain
+    /// parts of the `NSError` object that are useful.
+    NSError {
+        /// Represents the code. Some of these can be... archaic.
+        code: usize,
 
-    /// Maps over to `[NSError localizedDescription]`.
-    pub description: String
+        /// Represents the domain of the error.
+        domain: String,
+
+        /// Maps over to `[NSError localizedDescription]`.
+        description: String
+    }
 }
 
 impl Error {
@@ -31,19 +50,7 @@ impl Error {
     /// configure this. We pull out the information as it makes the error thread safe this way,
     /// which is... easier, in some cases.
     pub fn new(error: id) -> Self {
-        let (code, domain, description) = unsafe {
-            let code: usize = msg_send![error, code];
-            let domain = NSString::retain(msg_send![error, domain]);
-            let description = NSString::retain(msg_send![error, localizedDescription]);
-
-            (code, domain, description)
-        };
-
-        Error {
-            code,
-            domain: domain.to_string(),
-            description: description.to_string()
-        }
+        NSError::retain(error).into_rust_error()
     }
 
     /// Returns a boxed `Error`.
@@ -54,18 +61,33 @@ impl Error {
     /// Used for cases where we need to return an `NSError` back to the system (e.g, top-level
     /// error handling). We just create a new `NSError` so the `Error` crate can be mostly
     /// thread safe.
+    ///
+    /// Errors that didn't originate from an `NSError` use a `com.cacao-rs.error` domain.
     pub fn into_nserror(self) -> id {
-        unsafe {
-            let domain = NSString::new(&self.domain);
-            let code = self.code as NSInteger;
-            msg_send![class!(NSError), errorWithDomain:domain code:code userInfo:nil]
-        }
+        let description = self.to_string();
+
+        let (domain, code) = match self {
+            Error::NSError { code, domain, .. } => (domain, code as NSInteger),
+            _ => (CACAO_ERROR_DOMAIN.to_string(), 0)
+        };
+
+        let domain = NSString::new(&domain);
+        let mut user_info = NSMutableDictionary::new();
+        user_info.insert(NSString::new("NSLocalizedDescription"), &mut *NSString::new(&description));
+
+        unsafe { msg_send![class!(NSError), errorWithDomain:&*domain code:code userInfo:&*user_info] }
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description)
+        match self {
+            Error::NilPointer => write!(f, "An Objective-C call unexpectedly returned nil"),
+            Error::InvalidUtf8 => write!(f, "The string was not validly encoded"),
+            Error::InvalidUrl(url) => write!(f, "\"{}\" is not a valid URL", url),
+            Error::InvalidHexColor(hex) => write!(f, "\"{}\" is not a valid hex color", hex),
+            Error::NSError { description, .. } => write!(f, "{}", description)
+        }
     }
 }
 
@@ -8,8 +8,12 @@ use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
+use crate::filesystem::enums::ModalResponse;
 use crate::foundation::{id, nil, NSInteger, NSString, NO, YES};
 
+#[cfg(feature = "appkit")]
+use crate::appkit::window::Window;
+
 #[derive(Debug)]
 pub struct FileSavePanel {
     /// The internal Objective C `NSOpenPanel` instance.
@@ -77,8 +81,8 @@ impl FileSavePanel {
         self.can_create_directories = can_create;
     }
 
-    /// Shows the panel as a modal. Currently sheets are not supported, but you're free (and able
-    /// to) thread the Objective C calls yourself by using the panel field on this struct.
+    /// Shows the panel as a modal. If you have a `Window` to attach it to, you probably want
+    /// `begin_sheet()` instead.
     ///
     /// Note that this clones the underlying `NSOpenPanel` pointer. This is theoretically safe as
     /// the system runs and manages that in another process, and we're still abiding by the general
@@ -98,6 +102,32 @@ impl FileSavePanel {
             //let _: () = msg_send![&*self.panel, beginWithCompletionHandler:completion.copy()];
         }
     }
+
+    /// Shows the panel as a sheet on the given window. The handler is called with the chosen path
+    /// once the panel is dismissed, or `None` if the user cancelled.
+    ///
+    /// Note that this clones the underlying `NSSavePanel` pointer. This is theoretically safe as
+    /// the system runs and manages that in another process, and we're still abiding by the general
+    /// retain/ownership rules here.
+    #[cfg(feature = "appkit")]
+    pub fn begin_sheet<T, F>(&self, window: &Window<T>, handler: F)
+    where
+        F: Fn(Option<String>) + 'static
+    {
+        let panel = self.panel.clone();
+        let completion = ConcreteBlock::new(move |result: NSInteger| {
+            let response: ModalResponse = result.into();
+
+            handler(match response {
+                ModalResponse::Ok => get_url(&panel),
+                _ => None
+            });
+        });
+
+        unsafe {
+            let _: () = msg_send![&*self.panel, beginSheetModalForWindow:&*window.objc completionHandler:completion.copy()];
+        }
+    }
 }
 
 /// Retrieves the selected URLs from the provided panel.
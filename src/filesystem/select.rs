@@ -11,7 +11,7 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
 use crate::filesystem::enums::ModalResponse;
-use crate::foundation::{id, nil, NSInteger, NSString, NO, NSURL, YES};
+use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NO, NSURL, YES};
 
 #[cfg(feature = "appkit")]
 use crate::appkit::window::{Window, WindowDelegate};
@@ -89,6 +89,22 @@ impl FileSelectPanel {
         }
     }
 
+    /// Restricts the files the user can choose to those with the given extensions (e.g, `"png"`,
+    /// `"txt"`). Passing an empty slice allows any file type, which is the default.
+    pub fn set_allowed_file_types(&mut self, file_types: &[&str]) {
+        unsafe {
+            let _: () = match file_types.is_empty() {
+                true => msg_send![&*self.panel, setAllowedFileTypes: nil],
+
+                false => {
+                    let file_types: Vec<id> = file_types.iter().map(|file_type| NSString::new(file_type).into()).collect();
+                    let file_types = NSArray::from(file_types);
+                    msg_send![&*self.panel, setAllowedFileTypes:&*file_types]
+                }
+            };
+        }
+    }
+
     /// Sets whether the user can choose directories.
     pub fn set_can_choose_directories(&mut self, can_choose: bool) {
         unsafe {
@@ -166,6 +182,9 @@ impl FileSelectPanel {
     /// If you're using a `Window` without a delegate, you may need to opt to use the `show()`
     /// method.
     ///
+    /// The handler receives an empty `Vec` if the user cancels; if you need paths rather than
+    /// URLs, `NSURL::pathbuf()` will get you there.
+    ///
     /// Note that this clones the underlying `NSOpenPanel` pointer. This is theoretically safe as
     /// the system runs and manages that in another process, and we're still abiding by the general
     /// retain/ownership rules here.
@@ -25,13 +25,19 @@ impl NSArray {
     }
 
     /// In some cases, we're vended an `NSArray` by the system that we need to call retain on.
-    /// This handles that case.
+    /// This handles that case. Equivalent to `from_unretained()`.
     pub fn retain(array: id) -> Self {
+        NSArray::from_unretained(array)
+    }
+
+    /// Wraps an `NSArray` that we don't own (e.g, one returned from a getter, or autoreleased),
+    /// retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(array: id) -> Self {
         NSArray(unsafe { Id::from_ptr(array) })
     }
 
     /// In some cases, we're vended an `NSArray` by the system, and it's ideal to not retain that.
-    /// This handles that edge case.
+    /// This takes over ownership of an already-retained array without an additional retain.
     pub fn from_retained(array: id) -> Self {
         NSArray(unsafe { Id::from_retained_ptr(array) })
     }
@@ -41,21 +47,64 @@ impl NSArray {
         unsafe { msg_send![&*self.0, count] }
     }
 
+    /// Returns the object at `index`. Like `objectAtIndex:`, this panics (well, throws) if `index`
+    /// is out of bounds - use `count()`, or `iter()`.
+    pub fn object_at(&self, index: usize) -> id {
+        unsafe { msg_send![&*self.0, objectAtIndex: index] }
+    }
+
+    /// Returns an iterator over the objects in this array.
+    pub fn iter(&self) -> NSArrayIter<'_> {
+        NSArrayIter {
+            array: self,
+            index: 0,
+            count: self.count()
+        }
+    }
+
     /// A helper method for mapping over the backing `NSArray` items and producing a Rust `Vec<T>`.
     /// Often times we need to map in this framework to convert between Rust types, so isolating
     /// this out makes life much easier.
     pub fn map<T, F: Fn(id) -> T>(&self, transform: F) -> Vec<T> {
-        let count = self.count();
-        let objc = &*self.0;
-
         // I don't know if it's worth trying to get in with NSFastEnumeration here. I'm content to
         // just rely on Rust, but someone is free to profile it if they want.
-        (0..count)
-            .map(|index| {
-                let item: id = unsafe { msg_send![objc, objectAtIndex: index] };
-                transform(item)
-            })
-            .collect()
+        self.iter().map(transform).collect()
+    }
+}
+
+/// An iterator over the objects in an `NSArray`; see `NSArray::iter()`.
+#[derive(Debug)]
+pub struct NSArrayIter<'a> {
+    array: &'a NSArray,
+    index: usize,
+    count: usize
+}
+
+impl Iterator for NSArrayIter<'_> {
+    type Item = id;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let item = self.array.object_at(self.index);
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> IntoIterator for &'a NSArray {
+    type Item = id;
+    type IntoIter = NSArrayIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -32,8 +32,7 @@ impl AutoReleasePool {
     where
         F: Fn() + 'static
     {
-        let _pool = AutoReleasePool::new();
-        handler();
+        autoreleasepool(handler);
     }
 }
 
@@ -43,3 +42,23 @@ impl Drop for AutoReleasePool {
         let _: () = unsafe { msg_send![&*self.0, drain] };
     }
 }
+
+/// Runs `f` inside of an autorelease pool (via `objc_autoreleasePoolPush`/`objc_autoreleasePoolPop`),
+/// returning whatever it returns. This is the equivalent of `@autoreleasepool {}`.
+///
+/// Autoreleased objects otherwise pile up until the run loop drains the current pool; wrapping the
+/// body of a hot loop (e.g, building a large number of `NSString`s for a table) bounds peak memory.
+/// The pool is popped even if `f` panics.
+///
+/// ```rust,no_run
+/// use cacao::foundation::{autoreleasepool, NSString};
+///
+/// let rows: Vec<String> = (0..10_000).map(|i| format!("Row {}", i)).collect();
+///
+/// for row in &rows {
+///     let length = autoreleasepool(|| NSString::new(row).to_str().len());
+/// }
+/// ```
+pub fn autoreleasepool<R, F: FnOnce() -> R>(f: F) -> R {
+    objc::rc::autoreleasepool(f)
+}
@@ -2,17 +2,24 @@ use std::cell::Cell;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::ffi::CString;
+use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::sync::{Arc, RwLock};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use std::time::Instant;
 
 use lazy_static::lazy_static;
 use objc::declare::ClassDecl;
-use objc::runtime::{objc_getClass, Class};
+use objc::runtime::{objc_getClass, Class, Object, Sel};
+use objc::Encode;
 
 lazy_static! {
     static ref CLASSES: ClassMap = ClassMap::new();
+
+    /// Serializes class registration, so that two threads racing to register the same subclass
+    /// don't both declare it.
+    static ref REGISTRATION: Mutex<()> = Mutex::new(());
 }
 
 thread_local! {
@@ -110,13 +117,104 @@ impl ClassMap {
     }
 }
 
+/// Wraps a `ClassDecl` while a class is being declared in `load_or_register_class()`.
+///
+/// This derefs to the underlying `ClassDecl`, so `add_ivar()`, `add_method()` and so on work as
+/// they always have. On top of that, the `add_methodN()` helpers take method implementations
+/// typed with the implicit `self` and `_cmd` arguments, which avoids having to cast each function
+/// to the right `extern "C" fn` pointer type by hand:
+///
+/// ```rust,no_run
+/// use cacao::foundation::{id, load_or_register_class};
+/// use objc::runtime::{Object, Sel};
+/// use objc::{sel, sel_impl};
+///
+/// extern "C" fn did_change(_this: &Object, _: Sel, _notification: id) {}
+///
+/// let class = load_or_register_class("NSObject", "MyObserver", |decl| {
+///     decl.add_method1(sel!(didChange:), did_change);
+/// });
+/// ```
+///
+/// Methods that need a mutable receiver (e.g, `dealloc`) should still go through `add_method()`.
+pub struct ClassBuilder(ClassDecl);
+
+impl ClassBuilder {
+    /// Adds a method that takes no arguments (beyond `self` and `_cmd`).
+    ///
+    /// Panics if `sel` doesn't take zero arguments.
+    pub fn add_method0<R: Encode>(&mut self, sel: Sel, imp: extern "C" fn(&Object, Sel) -> R) {
+        unsafe {
+            self.0.add_method(sel, imp);
+        }
+    }
+
+    /// Adds a method that takes one argument (beyond `self` and `_cmd`).
+    ///
+    /// Panics if `sel` doesn't take one argument.
+    pub fn add_method1<A: Encode, R: Encode>(&mut self, sel: Sel, imp: extern "C" fn(&Object, Sel, A) -> R) {
+        unsafe {
+            self.0.add_method(sel, imp);
+        }
+    }
+
+    /// Adds a method that takes two arguments (beyond `self` and `_cmd`).
+    ///
+    /// Panics if `sel` doesn't take two arguments.
+    pub fn add_method2<A: Encode, B: Encode, R: Encode>(&mut self, sel: Sel, imp: extern "C" fn(&Object, Sel, A, B) -> R) {
+        unsafe {
+            self.0.add_method(sel, imp);
+        }
+    }
+
+    /// Adds a method that takes three arguments (beyond `self` and `_cmd`).
+    ///
+    /// Panics if `sel` doesn't take three arguments.
+    pub fn add_method3<A: Encode, B: Encode, C: Encode, R: Encode>(
+        &mut self,
+        sel: Sel,
+        imp: extern "C" fn(&Object, Sel, A, B, C) -> R
+    ) {
+        unsafe {
+            self.0.add_method(sel, imp);
+        }
+    }
+}
+
+impl fmt::Debug for ClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClassBuilder").finish()
+    }
+}
+
+impl Deref for ClassBuilder {
+    type Target = ClassDecl;
+
+    /// Derefs to the underlying `ClassDecl`.
+    fn deref(&self) -> &ClassDecl {
+        &self.0
+    }
+}
+
+impl DerefMut for ClassBuilder {
+    /// Derefs to the underlying `ClassDecl`.
+    fn deref_mut(&mut self) -> &mut ClassDecl {
+        &mut self.0
+    }
+}
+
 /// Attempts to load a subclass, given a `superclass_name` and subclass_name. If
 /// the subclass cannot be loaded, it's dynamically created and injected into
 /// the runtime, and then returned. The returned value can be used for allocating new instances of
 /// this class in the Objective-C runtime.
 ///
 /// The `config` block can be used to customize the Class declaration before it's registered with
-/// the runtime. This is useful for adding method handlers and ivar storage.
+/// the runtime. This is useful for adding method handlers and ivar storage; see `ClassBuilder`.
+///
+/// Note that the Objective-C object can outlive whatever Rust type wraps it. Classes that own
+/// values stored in ivars (e.g, closures) should drop them from a `dealloc` override (see
+/// `DelegateContainer`); pointers to delegates owned on the Rust side are instead cleared by the
+/// owning type when it drops (see `utils::clear_ptr()`), and read back via `utils::load_opt()`.
 ///
 /// If the superclass cannot be loaded, this will panic. If the subclass cannot be
 /// created, this will panic. In general, this is expected to work, and if it doesn't,
@@ -126,12 +224,21 @@ impl ClassMap {
 #[inline(always)]
 pub fn load_or_register_class<F>(superclass_name: &'static str, subclass_name: &'static str, config: F) -> *const Class
 where
-    F: Fn(&mut ClassDecl) + 'static
+    F: Fn(&mut ClassBuilder) + 'static
 {
     if let Some(subclass) = CLASSES.load(subclass_name, Some(superclass_name)) {
         return subclass;
     }
 
+    // Another thread may have registered this class between the check above and acquiring the
+    // lock, so check again once we hold it. `config` must not register classes itself, as this
+    // lock isn't reentrant.
+    let _registration = REGISTRATION.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Some(subclass) = CLASSES.load(subclass_name, Some(superclass_name)) {
+        return subclass;
+    }
+
     // If we can't find the class anywhere, then we'll attempt to load the superclass and register
     // our new class type.
     if let Some(superclass) = CLASSES.load(superclass_name, None) {
@@ -154,10 +261,11 @@ where
         );
 
         match ClassDecl::new(&objc_subclass_name, unsafe { &*superclass }) {
-            Some(mut decl) => {
-                config(&mut decl);
+            Some(decl) => {
+                let mut builder = ClassBuilder(decl);
+                config(&mut builder);
 
-                let class = decl.register();
+                let class = builder.0.register();
                 CLASSES.store(subclass_name, Some(superclass_name), class);
                 return class;
             },
@@ -176,3 +284,14 @@ where
         subclass_name, superclass_name
     );
 }
+
+#[cfg(all(feature = "appkit", target_os = "macos"))]
+#[test]
+fn test_concurrent_registration_yields_one_class() {
+    let handles: Vec<_> = (0..8)
+        .map(|_| thread::spawn(|| load_or_register_class("NSObject", "RSTConcurrentRegistrationTest", |_| {}) as usize))
+        .collect();
+
+    let classes: Vec<usize> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    assert!(classes.iter().all(|class| *class == classes[0]));
+}
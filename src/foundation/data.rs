@@ -1,3 +1,4 @@
+use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ops::{Deref, DerefMut};
 use std::os::raw::c_void;
@@ -65,13 +66,20 @@ impl NSData {
         }
     }
 
-    /// Given a (presumably) `NSData`, wraps and retains it.
+    /// Given a (presumably) `NSData`, wraps and retains it. Equivalent to `from_unretained()`.
     pub fn retain(data: id) -> Self {
+        NSData::from_unretained(data)
+    }
+
+    /// Wraps an `NSData` that we don't own (e.g, one returned from a getter, or autoreleased),
+    /// retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(data: id) -> Self {
         NSData(unsafe { Id::from_ptr(data) })
     }
 
     /// If we're vended an NSData from a method (e.g, a push notification token) we might want to
-    /// wrap it while we figure out what to do with it. This does that.
+    /// wrap it while we figure out what to do with it. This takes over ownership of an
+    /// already-retained `NSData` without an additional retain.
     pub fn from_retained(data: id) -> Self {
         NSData(unsafe { Id::from_retained_ptr(data) })
     }
@@ -91,6 +99,11 @@ impl NSData {
         }
     }
 
+    /// Returns whether the underlying `NSData` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Returns a reference to the underlying bytes for the wrapped `NSData`.
     ///
     /// This, like `NSData::new()`, is cribbed from [objc-foundation](objc-foundation).
@@ -117,13 +130,14 @@ impl NSData {
     // this data without messing up the Objective-C side of things. Thankfully this isn't used too
     // often, but still... open to ideas.
     pub fn into_vec(self) -> Vec<u8> {
-        let mut data = Vec::new();
-
-        let bytes = self.bytes();
-        data.resize(bytes.len(), 0);
-        data.copy_from_slice(bytes);
+        self.to_vec()
+    }
 
-        data
+    /// Copies the underlying bytes into a new Vec, leaving this `NSData` intact. The copy is
+    /// independent of the `NSData`, so it's safe to release it afterwards. Empty data (where
+    /// `bytes` may be null) returns an empty Vec.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes().to_vec()
     }
 }
 
@@ -134,6 +148,25 @@ impl From<NSData> for id {
     }
 }
 
+impl PartialEq for NSData {
+    /// Compares the bytes held by both `NSData` instances, via `isEqualToData:`.
+    fn eq(&self, other: &Self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.0, isEqualToData:&*other.0] };
+        to_bool(result)
+    }
+}
+
+impl Eq for NSData {}
+
+impl Hash for NSData {
+    /// Feeds the Foundation-provided `hash` into `state`. Foundation guarantees that equal data
+    /// objects produce equal hashes.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash: NSUInteger = unsafe { msg_send![&*self.0, hash] };
+        hash.hash(state);
+    }
+}
+
 impl Deref for NSData {
     type Target = Object;
 
@@ -0,0 +1,77 @@
+//! A small utility for storing Rust values - typically boxed closures - on Objective-C objects.
+//!
+//! The general pattern throughout this crate is to box a value, stash the (thin) pointer in a
+//! `usize` ivar, and reconstruct a reference to it whenever the Objective-C side calls back in.
+//! Doing that by hand in each module makes it easy to leak (forgetting to reclaim the box) or
+//! double-free (reclaiming it twice), so this standardizes storing, replacing, and dropping in one
+//! place. Classes that use it should call `DelegateContainer::clear()` from their `dealloc`.
+//!
+//! Values are reference counted, and `get()` hands back a clone of the stored `Rc`. This matters
+//! for closures: a handler can replace itself (e.g, a button action that sets a new action) while
+//! it's running, and the clone keeps the running one alive until it returns.
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use objc::runtime::Object;
+
+/// A boxed closure that takes no arguments; the most common thing we store.
+pub(crate) type Callback = Box<dyn Fn() + 'static>;
+
+/// Stores a value of type `T` in a `usize` ivar on an Objective-C object. `T` must be `Sized`, so
+/// for trait objects (e.g, closures), store a `Box<dyn Fn()>` - see `Callback`.
+#[derive(Debug)]
+pub(crate) struct DelegateContainer<T>(PhantomData<T>);
+
+impl<T> DelegateContainer<T> {
+    /// Stores `value` in the ivar named `ivar`, dropping the container's reference to any value
+    /// that was already stored there.
+    ///
+    /// The ivar must have been declared as a `usize`, and if it's set, must hold a `T` that was
+    /// stored via this method.
+    pub unsafe fn set(this: &mut Object, ivar: &str, value: T) {
+        DelegateContainer::<T>::clear(this, ivar);
+
+        let ptr = Rc::into_raw(Rc::new(value));
+        this.set_ivar(ivar, ptr as usize);
+    }
+
+    /// Returns a new reference to the value stored in the ivar named `ivar`, if one has been set.
+    /// Hold on to it for as long as you're using the value; it'll outlive a concurrent `set()` or
+    /// `clear()` on the same ivar (e.g, from within a handler).
+    ///
+    /// As with `set()`, the ivar must be a `usize` holding a `T` stored via `set()`. This isn't
+    /// thread safe; the caller must ensure the ivar isn't being modified on another thread.
+    pub unsafe fn get(this: &Object, ivar: &str) -> Option<Rc<T>> {
+        let ptr: usize = *this.get_ivar(ivar);
+
+        match ptr {
+            0 => None,
+            ptr => {
+                let ptr = ptr as *const T;
+                Rc::increment_strong_count(ptr);
+                Some(Rc::from_raw(ptr))
+            }
+        }
+    }
+
+    /// Removes the value stored in the ivar named `ivar`, if there is one, and zeroes the ivar.
+    /// The container's reference is handed back to the caller.
+    pub unsafe fn take(this: &mut Object, ivar: &str) -> Option<Rc<T>> {
+        let ptr: usize = *this.get_ivar(ivar);
+
+        match ptr {
+            0 => None,
+            ptr => {
+                this.set_ivar(ivar, 0usize);
+                Some(Rc::from_raw(ptr as *const T))
+            }
+        }
+    }
+
+    /// Drops the container's reference to the value stored in the ivar named `ivar`, if there is
+    /// one, and zeroes the ivar so that it can't be dropped twice. Call this from `dealloc`.
+    pub unsafe fn clear(this: &mut Object, ivar: &str) {
+        let _value = DelegateContainer::<T>::take(this, ivar);
+    }
+}
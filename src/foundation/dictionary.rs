@@ -3,9 +3,148 @@ use std::ops::{Deref, DerefMut};
 
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
-use objc_id::Id;
+use objc_id::{Id, ShareId};
 
-use crate::foundation::{id, NSString};
+use crate::foundation::{id, nil, to_bool, NSString, BOOL};
+
+/// A wrapper for `NSDictionary`. This is a shared (reference counted) handle - e.g, for reading
+/// dictionaries vended by the system, or building the `options` dictionaries that a number of
+/// AppKit APIs take.
+///
+/// Dictionaries created via `new()` are mutable; `insert()` panics if the backing dictionary is
+/// not (e.g, one wrapped via `retain()` from a system API).
+#[derive(Clone, Debug)]
+pub struct NSDictionary(pub ShareId<Object>);
+
+impl Default for NSDictionary {
+    /// Returns a blank (mutable) NSDictionary.
+    fn default() -> Self {
+        NSDictionary::new()
+    }
+}
+
+impl NSDictionary {
+    /// Creates a new, empty, mutable dictionary.
+    pub fn new() -> Self {
+        NSDictionary(unsafe { ShareId::from_retained_ptr(msg_send![class!(NSMutableDictionary), new]) })
+    }
+
+    /// In cases where we're vended an `NSDictionary` by the system, this can be used to wrap and
+    /// retain it. Equivalent to `from_unretained()`.
+    pub fn retain(dictionary: id) -> Self {
+        NSDictionary::from_unretained(dictionary)
+    }
+
+    /// Wraps an `NSDictionary` that we don't own (e.g, one returned from a getter, or
+    /// autoreleased), retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(dictionary: id) -> Self {
+        NSDictionary(unsafe { ShareId::from_ptr(dictionary) })
+    }
+
+    /// Wraps an `NSDictionary` that we already own (e.g, from `alloc`/`init`, `new`, or `copy`),
+    /// taking over that ownership without an additional retain.
+    pub fn from_retained(dictionary: id) -> Self {
+        NSDictionary(unsafe { ShareId::from_retained_ptr(dictionary) })
+    }
+
+    /// Sets `value` for `key`, replacing any existing value. Passing a `nil` value removes the
+    /// key instead.
+    ///
+    /// Panics if the backing dictionary is immutable.
+    pub fn insert(&self, key: &str, value: id) {
+        let mutable: BOOL = unsafe { msg_send![&*self.0, isKindOfClass: class!(NSMutableDictionary)] };
+        assert!(to_bool(mutable), "Attempted to insert into an immutable NSDictionary");
+
+        let key = NSString::new(key);
+
+        unsafe {
+            match value.is_null() {
+                true => {
+                    let _: () = msg_send![&*self.0, removeObjectForKey:&*key];
+                },
+
+                false => {
+                    let _: () = msg_send![&*self.0, setObject:value forKey:&*key];
+                }
+            }
+        }
+    }
+
+    /// Returns the object stored for the given key, if there is one.
+    pub fn get(&self, key: &str) -> Option<id> {
+        let key = NSString::new(key);
+        let object: id = unsafe { msg_send![&*self.0, objectForKey:&*key] };
+
+        match object.is_null() {
+            true => None,
+            false => Some(object)
+        }
+    }
+
+    /// Returns the number of entries in this dictionary.
+    pub fn len(&self) -> usize {
+        unsafe { msg_send![&*self.0, count] }
+    }
+
+    /// Returns whether this dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the key/value pairs in this dictionary, backed by
+    /// `keyEnumerator`. Keys that aren't strings are converted via `[NSObject description]`.
+    ///
+    /// As with `NSEnumerator`, the dictionary must not be mutated while iterating.
+    pub fn iter(&self) -> NSDictionaryIter<'_> {
+        NSDictionaryIter {
+            dictionary: self,
+            enumerator: unsafe { ShareId::from_ptr(msg_send![&*self.0, keyEnumerator]) }
+        }
+    }
+
+    /// Returns the underlying `NSDictionary`. This is still owned by (and will be released
+    /// with) this wrapper.
+    pub fn as_id(&self) -> id {
+        &*self.0 as *const Object as id
+    }
+}
+
+/// An iterator over the key/value pairs in an `NSDictionary`; see `NSDictionary::iter()`.
+#[derive(Debug)]
+pub struct NSDictionaryIter<'a> {
+    dictionary: &'a NSDictionary,
+    enumerator: ShareId<Object>
+}
+
+impl Iterator for NSDictionaryIter<'_> {
+    type Item = (String, id);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key: id = unsafe { msg_send![&*self.enumerator, nextObject] };
+
+        if key == nil {
+            return None;
+        }
+
+        let value: id = unsafe { msg_send![&*self.dictionary.0, objectForKey: key] };
+
+        let key = match NSString::is(key) {
+            true => NSString::retain(key).to_string(),
+            false => NSString::retain(unsafe { msg_send![key, description] }).to_string()
+        };
+
+        Some((key, value))
+    }
+}
+
+impl<'a> IntoIterator for &'a NSDictionary {
+    type Item = (String, id);
+    type IntoIter = NSDictionaryIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
 /// A wrapper for `NSMutableDictionary`.
 #[derive(Debug)]
@@ -29,6 +168,18 @@ impl NSMutableDictionary {
         NSMutableDictionary(unsafe { Id::from_ptr(msg_send![class!(NSMutableDictionary), new]) })
     }
 
+    /// Wraps an `NSMutableDictionary` that we don't own (e.g, one returned from a getter, or
+    /// autoreleased), retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(dictionary: id) -> Self {
+        NSMutableDictionary(unsafe { Id::from_ptr(dictionary) })
+    }
+
+    /// Wraps an `NSMutableDictionary` that we already own (e.g, from `new` or `mutableCopy`),
+    /// taking over that ownership without an additional retain.
+    pub fn from_retained(dictionary: id) -> Self {
+        NSMutableDictionary(unsafe { Id::from_retained_ptr(dictionary) })
+    }
+
     /// Inserts an object into the backing NSMutablyDictionary.
     ///
     /// This intentionally requires `NSString` be allocated ahead of time.
@@ -38,6 +189,17 @@ impl NSMutableDictionary {
         }
     }
 
+    /// Returns the object stored for the given key, if there is one.
+    pub fn get(&self, key: &str) -> Option<id> {
+        let key = NSString::new(key);
+        let object: id = unsafe { msg_send![&*self.0, objectForKey:&*key] };
+
+        match object.is_null() {
+            true => None,
+            false => Some(object)
+        }
+    }
+
     /// Consumes and returns the underlying `NSMutableDictionary`.
     pub fn into_inner(mut self) -> id {
         &mut *self.0
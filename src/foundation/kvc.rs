@@ -0,0 +1,281 @@
+//! A small, generic key-value coding (KVC) escape hatch. This is useful for properties that we
+//! don't (yet) wrap - e.g, private flags like `drawsBackground` on `WKWebView` - without having
+//! to scatter raw `setValue:forKey:` calls around your codebase.
+//!
+//! ```rust,no_run
+//! use cacao::foundation::{id, kvc, kvc::KvcValue};
+//!
+//! # let object: id = cacao::foundation::nil;
+//! kvc::set_value(object, "drawsBackground", KvcValue::Bool(false)).expect("Unknown key!");
+//! let draws_background = kvc::value_for_key(object, "drawsBackground").unwrap();
+//! ```
+//!
+//! ## Unknown keys
+//! Objective-C will throw an `NSUndefinedKeyException` if you attempt to get or set a key that an
+//! object doesn't know about. Exceptions can't safely unwind through Rust, so rather than calling
+//! through and crashing, we mirror the accessor search that Foundation does (`set<Key>:`,
+//! `_set<Key>:`, `<key>`, `is<Key>`, instance variables, and so on) and return a `KvcError` if
+//! nothing matches. Objects that provide their own `valueForKey:`/`setValue:forKey:` (or undefined
+//! key) handling - e.g, `NSDictionary` - are always passed through.
+//!
+//! Note that setting `KvcValue::Nil` on a scalar property will still throw, as Foundation routes
+//! that to `setNilValueForKey:`.
+
+use std::error;
+use std::fmt;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, to_bool, NSNumber, NSString, BOOL};
+
+/// Represents a value that can be passed to, or returned from, key-value coding calls.
+#[derive(Clone, Debug)]
+pub enum KvcValue {
+    /// Represents `nil`.
+    Nil,
+
+    /// Represents a Boolean value. Boxed as an `NSNumber`.
+    Bool(bool),
+
+    /// Represents a String value. Boxed as an `NSString`.
+    String(String),
+
+    /// Represents a Float (`f64`) value. Boxed as an `NSNumber`.
+    Float(f64),
+
+    /// Represents an Integer (`i64`) value. Boxed as an `NSNumber`.
+    Integer(i64),
+
+    /// Represents any other Objective-C object.
+    Object(ShareId<Object>)
+}
+
+impl KvcValue {
+    /// A handy initializer for `KvcValue::String`.
+    pub fn string<S: Into<String>>(value: S) -> Self {
+        KvcValue::String(value.into())
+    }
+
+    /// Retains and wraps an arbitrary Objective-C object. A `nil` pointer becomes `KvcValue::Nil`.
+    pub fn object(value: id) -> Self {
+        match value.is_null() {
+            true => KvcValue::Nil,
+            false => KvcValue::Object(unsafe { ShareId::from_ptr(value) })
+        }
+    }
+
+    /// If this is a Bool, it returns the associated bool. Returns `None` otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            KvcValue::Bool(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    /// If this is a String, it returns a &str. Returns `None` otherwise.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            KvcValue::String(s) => Some(s),
+            _ => None
+        }
+    }
+
+    /// If this is a Float, it returns the associated `f64`. Returns `None` otherwise.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            KvcValue::Float(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    /// If this is an Integer, it returns the associated `i64`. Returns `None` otherwise.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            KvcValue::Integer(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    /// Given an `id` returned from `valueForKey:`, inspects it and converts it to the closest
+    /// matching `KvcValue`.
+    fn from_id(value: id) -> Self {
+        if value == nil {
+            return KvcValue::Nil;
+        }
+
+        if NSString::is(value) {
+            return KvcValue::String(NSString::retain(value).to_string());
+        }
+
+        // Mirrors the approach in `UserDefaults::get()`; see the notes there.
+        if NSNumber::is(value) {
+            let number = NSNumber::retain(value);
+
+            return match number.objc_type() {
+                "c" | "B" => KvcValue::Bool(number.as_bool()),
+                "d" | "f" => KvcValue::Float(number.as_f64()),
+                _ => KvcValue::Integer(number.as_i64())
+            };
+        }
+
+        KvcValue::object(value)
+    }
+}
+
+/// Errors that can be returned from key-value coding calls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KvcError {
+    /// The object has no accessor or instance variable matching this key. Calling through would
+    /// have thrown an `NSUndefinedKeyException`.
+    UndefinedKey(String)
+}
+
+impl fmt::Display for KvcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KvcError::UndefinedKey(key) => write!(f, "This object is not key-value compliant for the key \"{}\"", key)
+        }
+    }
+}
+
+impl error::Error for KvcError {}
+
+/// Sets `value` for `key` on `object`, via `setValue:forKey:`.
+///
+/// Returns `KvcError::UndefinedKey` if `object` has no setter or instance variable for `key`.
+pub fn set_value(object: id, key: &str, value: KvcValue) -> Result<(), KvcError> {
+    let capitalized = capitalize(key);
+    let setters = [format!("set{}:", capitalized), format!("_set{}:", capitalized)];
+
+    if !handles_key(
+        object,
+        sel!(setValue:forKey:),
+        sel!(setValue:forUndefinedKey:),
+        &setters,
+        key,
+        &capitalized
+    ) {
+        return Err(KvcError::UndefinedKey(key.to_string()));
+    }
+
+    let k = NSString::new(key);
+
+    unsafe {
+        match value {
+            KvcValue::Nil => {
+                let _: () = msg_send![object, setValue:nil forKey:&*k];
+            },
+
+            KvcValue::Bool(b) => {
+                let v = NSNumber::bool(b);
+                let _: () = msg_send![object, setValue:&*v.0 forKey:&*k];
+            },
+
+            KvcValue::String(s) => {
+                let v = NSString::new(&s);
+                let _: () = msg_send![object, setValue:&*v forKey:&*k];
+            },
+
+            KvcValue::Float(f) => {
+                let v = NSNumber::float(f);
+                let _: () = msg_send![object, setValue:&*v.0 forKey:&*k];
+            },
+
+            KvcValue::Integer(i) => {
+                let v = NSNumber::integer(i);
+                let _: () = msg_send![object, setValue:&*v.0 forKey:&*k];
+            },
+
+            KvcValue::Object(obj) => {
+                let _: () = msg_send![object, setValue:&*obj forKey:&*k];
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the value for `key` on `object`, via `valueForKey:`.
+///
+/// Returns `KvcError::UndefinedKey` if `object` has no getter or instance variable for `key`.
+pub fn value_for_key(object: id, key: &str) -> Result<KvcValue, KvcError> {
+    let capitalized = capitalize(key);
+    let getters = [
+        format!("get{}", capitalized),
+        key.to_string(),
+        format!("is{}", capitalized),
+        format!("_{}", key)
+    ];
+
+    if !handles_key(
+        object,
+        sel!(valueForKey:),
+        sel!(valueForUndefinedKey:),
+        &getters,
+        key,
+        &capitalized
+    ) {
+        return Err(KvcError::UndefinedKey(key.to_string()));
+    }
+
+    let k = NSString::new(key);
+    let value: id = unsafe { msg_send![object, valueForKey:&*k] };
+
+    Ok(KvcValue::from_id(value))
+}
+
+/// Uppercases the first character of a key, as KVC does when building accessor names.
+fn capitalize(key: &str) -> String {
+    let mut chars = key.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new()
+    }
+}
+
+/// Determines whether calling through to KVC for this key would succeed, rather than throw.
+fn handles_key(object: id, entry: Sel, undefined: Sel, accessors: &[String], key: &str, capitalized: &str) -> bool {
+    if object.is_null() {
+        return false;
+    }
+
+    let class: &Class = unsafe { msg_send![object, class] };
+
+    if overrides(class, entry) || overrides(class, undefined) {
+        return true;
+    }
+
+    let responds = accessors.iter().any(|name| {
+        let sel = Sel::register(name);
+        let result: BOOL = unsafe { msg_send![object, respondsToSelector: sel] };
+        to_bool(result)
+    });
+
+    if responds {
+        return true;
+    }
+
+    let direct: BOOL = unsafe { msg_send![class, accessInstanceVariablesDirectly] };
+
+    to_bool(direct)
+        && [
+            format!("_{}", key),
+            format!("_is{}", capitalized),
+            key.to_string(),
+            format!("is{}", capitalized)
+        ]
+        .iter()
+        .any(|name| class.instance_variable(name).is_some())
+}
+
+/// Returns whether `class` provides its own implementation of `sel`, rather than inheriting the
+/// (throwing) one from `NSObject`.
+fn overrides(class: &Class, sel: Sel) -> bool {
+    let base = class!(NSObject).instance_method(sel).map(|m| m.implementation() as usize);
+    let ours = class.instance_method(sel).map(|m| m.implementation() as usize);
+
+    ours != base
+}
@@ -0,0 +1,209 @@
+//! A generic key-value observing (KVO) utility. This is used internally to build property
+//! observation (e.g, loading progress) on top of, but is exposed for cases where you need to
+//! observe something that we don't (yet) wrap.
+//!
+//! ```rust,no_run
+//! use cacao::foundation::{id, KeyValueObserver, NSKeyValueObservingOption};
+//! use cacao::objc::{class, msg_send, sel, sel_impl};
+//!
+//! let progress: id = unsafe { msg_send![class!(NSProgress), progressWithTotalUnitCount: 100i64] };
+//!
+//! // Keep the token around for as long as you want to observe changes; dropping it removes the
+//! // observer.
+//! let token = KeyValueObserver::observe(progress, "fractionCompleted", &[NSKeyValueObservingOption::New], |change| {
+//!     let new_value = change.get("new");
+//! });
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use lazy_static::lazy_static;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::{Id, ShareId};
+
+use crate::foundation::{id, load_or_register_class, nil, DelegateContainer, NSMutableDictionary, NSString, NSUInteger};
+
+pub(crate) static KVO_HANDLER_PTR: &str = "rstKVOHandlerPtr";
+
+lazy_static! {
+    /// Guards the handler ivar on every observer. Changes can be delivered on any thread, so
+    /// loading (and cloning) the handler has to be serialized against the observer dropping it.
+    static ref HANDLER_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// Options that control what's included in the change dictionary passed to your handler, and
+/// when it's called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NSKeyValueObservingOption {
+    /// The change dictionary should include the new value, under the `"new"` key.
+    New,
+
+    /// The change dictionary should include the old value, under the `"old"` key.
+    Old,
+
+    /// The handler should be called once immediately, before `observe()` returns.
+    Initial,
+
+    /// The handler should be called both before and after each change, rather than just after.
+    Prior
+}
+
+impl From<&NSKeyValueObservingOption> for NSUInteger {
+    fn from(option: &NSKeyValueObservingOption) -> NSUInteger {
+        match option {
+            NSKeyValueObservingOption::New => 1 << 0,
+            NSKeyValueObservingOption::Old => 1 << 1,
+            NSKeyValueObservingOption::Initial => 1 << 2,
+            NSKeyValueObservingOption::Prior => 1 << 3
+        }
+    }
+}
+
+/// The handler type we store on the observer object. Change notifications clone this out (under
+/// `HANDLER_LOCK`) before calling it, so a call in progress keeps the handler alive if the token
+/// is dropped on another thread in the meantime.
+type Handler = Arc<dyn Fn(NSMutableDictionary) + 'static>;
+
+/// Handles installing key-value observers.
+#[derive(Debug)]
+pub struct KeyValueObserver;
+
+impl KeyValueObserver {
+    /// Observes changes to the value at `key_path` on `object`, calling `handler` with the change
+    /// dictionary each time it changes. What's in the dictionary depends on the `options` passed;
+    /// the `"kind"` key is always present.
+    ///
+    /// The handler is called on whatever thread the change happens on, hence the `Send + Sync`
+    /// requirement. The observed object is retained for as long as the returned `ObserverToken`
+    /// is alive; dropping the token removes the observer. Returns `None` if `object` is `nil`.
+    pub fn observe<F>(object: id, key_path: &str, options: &[NSKeyValueObservingOption], handler: F) -> Option<ObserverToken>
+    where
+        F: Fn(NSMutableDictionary) + Send + Sync + 'static
+    {
+        unsafe { KeyValueObserver::observe_unchecked(object, key_path, options, handler) }
+    }
+
+    /// As with `observe()`, but without requiring the handler to be thread safe.
+    ///
+    /// The caller must guarantee that `object` only changes on the main thread (e.g, WebKit's
+    /// UI-facing properties), and that the returned token is dropped on the main thread.
+    pub(crate) unsafe fn observe_unchecked<F>(
+        object: id,
+        key_path: &str,
+        options: &[NSKeyValueObservingOption],
+        handler: F
+    ) -> Option<ObserverToken>
+    where
+        F: Fn(NSMutableDictionary) + 'static
+    {
+        if object.is_null() {
+            return None;
+        }
+
+        let handler: Handler = Arc::new(handler);
+
+        let mut opts: NSUInteger = 0;
+        for option in options {
+            let o: NSUInteger = option.into();
+            opts |= o;
+        }
+
+        let key_path = key_path.to_string();
+        let path = NSString::new(&key_path);
+
+        let observer: id = msg_send![register_observer_class(), new];
+        DelegateContainer::set(&mut *observer, KVO_HANDLER_PTR, handler);
+
+        let _: () = msg_send![object, addObserver:observer forKeyPath:&*path options:opts context:nil];
+
+        Some(ObserverToken {
+            observer: Id::from_retained_ptr(observer),
+            object: ShareId::from_ptr(object),
+            key_path
+        })
+    }
+}
+
+/// Returned from `KeyValueObserver::observe()`. Removes the observer when dropped.
+pub struct ObserverToken {
+    observer: Id<Object>,
+    object: ShareId<Object>,
+    key_path: String
+}
+
+impl fmt::Debug for ObserverToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObserverToken")
+            .field("object", &self.object)
+            .field("key_path", &self.key_path)
+            .finish()
+    }
+}
+
+impl Drop for ObserverToken {
+    /// Removes the observer from the observed object. The observer is then released, and drops
+    /// the handler when it's deallocated.
+    fn drop(&mut self) {
+        let path = NSString::new(&self.key_path);
+
+        unsafe {
+            let _: () = msg_send![&*self.object, removeObserver:&*self.observer forKeyPath:&*path];
+        }
+    }
+}
+
+/// Called by the runtime whenever an observed key path changes.
+extern "C" fn observe_value(this: &Object, _: Sel, _key_path: id, _object: id, change: id, _context: id) {
+    let handler = {
+        let _lock = HANDLER_LOCK.lock().unwrap();
+
+        // The stored handler's count isn't atomic, so this must be dropped before the lock is.
+        let stored = unsafe { DelegateContainer::<Handler>::get(this, KVO_HANDLER_PTR) };
+        let handler = stored.as_deref().cloned();
+        drop(stored);
+        handler
+    };
+
+    let handler = match handler {
+        Some(handler) => handler,
+        None => return
+    };
+
+    let change = match change {
+        c if c == nil => NSMutableDictionary::new(),
+        c => NSMutableDictionary::from_retained(unsafe { msg_send![c, mutableCopy] })
+    };
+
+    handler(change);
+}
+
+/// Drops the handler when the observer is deallocated. The handler is taken under the lock, but
+/// dropped outside of it, as dropping it can drop other tokens.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        let handler = {
+            let _lock = HANDLER_LOCK.lock().unwrap();
+            DelegateContainer::<Handler>::take(this, KVO_HANDLER_PTR)
+        };
+
+        drop(handler);
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers an `NSObject` subclass that forwards KVO callbacks to a Rust handler.
+fn register_observer_class() -> *const Class {
+    load_or_register_class("NSObject", "RSTKeyValueObserver", |decl| unsafe {
+        decl.add_ivar::<usize>(KVO_HANDLER_PTR);
+
+        decl.add_method(
+            sel!(observeValueForKeyPath:ofObject:change:context:),
+            observe_value as extern "C" fn(&Object, _, _, _, _, _)
+        );
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, _));
+    })
+}
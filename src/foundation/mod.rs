@@ -14,6 +14,14 @@
 //! cases, but I found it frustrating and messy trying to work with them. These provide the API I
 //! was looking for, and help provide all the proper `retain`/`release` logic needed for the
 //! Objective-C side.
+//!
+//! ## Ownership
+//! Wrappers that hold an object take it in one of two ways: `from_retained()` takes over a
+//! reference you already own (e.g, from `alloc`/`init`, `new`, or `copy`), and `from_unretained()`
+//! retains one you don't (e.g, a getter's return value). Mixing these up shows up as a leak or an
+//! over-release; the latter usually crashes somewhere unrelated, when a pool drains. If you're
+//! chasing one, run with `NSZombieEnabled=YES` in the environment, and the runtime will report the
+//! message sent to the freed object.
 
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
@@ -22,19 +30,32 @@ use objc::runtime;
 pub use objc::runtime::{BOOL, NO, YES};
 
 mod autoreleasepool;
-pub use autoreleasepool::AutoReleasePool;
+pub use autoreleasepool::{autoreleasepool, AutoReleasePool};
 
 mod array;
-pub use array::NSArray;
+pub use array::{NSArray, NSArrayIter};
 
 mod class;
-pub use class::load_or_register_class;
+pub use class::{load_or_register_class, ClassBuilder};
 
 mod data;
 pub use data::NSData;
 
+mod delegate_container;
+pub(crate) use delegate_container::{Callback, DelegateContainer};
+
 mod dictionary;
-pub use dictionary::NSMutableDictionary;
+pub use dictionary::{NSDictionary, NSDictionaryIter, NSMutableDictionary};
+
+pub mod kvc;
+
+mod kvo;
+pub use kvo::{KeyValueObserver, NSKeyValueObservingOption, ObserverToken};
+
+pub mod msg;
+
+mod nserror;
+pub use nserror::NSError;
 
 mod number;
 pub use number::NSNumber;
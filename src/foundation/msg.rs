@@ -0,0 +1,64 @@
+//! Typed helpers for sending one-off, argument-less messages - e.g, reading a property that we
+//! don't (yet) wrap - without reaching for `msg_send!` and marshalling the result yourself.
+//!
+//! ```rust,no_run
+//! use objc::{sel, sel_impl};
+//! use cacao::foundation::{id, msg};
+//!
+//! # let window: id = cacao::foundation::nil;
+//! let is_visible = msg::send_bool(window, sel!(isVisible));
+//! let title = msg::send_string(window, sel!(title));
+//! ```
+//!
+//! ## Safety
+//! Sending a message with the wrong return type is undefined behavior, and sending a selector an
+//! object doesn't implement throws. To keep these safe to call, each helper checks that `object`
+//! implements `selector` with a matching return type, and panics if it doesn't - much like
+//! `msg_send!` panics on a failed send. Messaging `nil` is allowed, and (as in Objective-C)
+//! returns `false`, `0`, or `None`.
+
+use std::any::Any;
+
+use objc::runtime::{Object, Sel};
+use objc::{Encode, Message};
+
+use crate::foundation::{id, to_bool, NSInteger, NSString, BOOL};
+
+/// Sends `selector` to `object`, which should return a `BOOL`.
+pub fn send_bool(object: id, selector: Sel) -> bool {
+    send::<BOOL>(object, selector).map(to_bool).unwrap_or(false)
+}
+
+/// Sends `selector` to `object`, which should return an `NSString`. Returns `None` if either the
+/// object or the returned string is `nil`.
+pub fn send_string(object: id, selector: Sel) -> Option<String> {
+    send::<id>(object, selector).and_then(NSString::to_string_opt)
+}
+
+/// Sends `selector` to `object`, which should return an `NSInteger`.
+pub fn send_i64(object: id, selector: Sel) -> i64 {
+    send::<NSInteger>(object, selector).map(|value| value as i64).unwrap_or(0)
+}
+
+/// Sends `selector` to `object`, which should return nothing (`void`).
+pub fn send_void(object: id, selector: Sel) {
+    send::<()>(object, selector);
+}
+
+/// Verifies the method signature and sends the message, returning `None` if `object` is `nil`.
+fn send<R: Any + Encode>(object: id, selector: Sel) -> Option<R> {
+    if object.is_null() {
+        return None;
+    }
+
+    let object: &Object = unsafe { &*object };
+
+    if let Err(e) = object.verify_message::<(), R>(selector) {
+        panic!("Unable to send {:?} to {:?}: {}", selector, object.class().name(), e);
+    }
+
+    match unsafe { object.send_message::<(), R>(selector, ()) } {
+        Ok(value) => Some(value),
+        Err(e) => panic!("Unable to send {:?} to {:?}: {}", selector, object.class().name(), e)
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::Deref;
+
+use objc::runtime::Object;
+use objc::{msg_send, sel, sel_impl};
+use objc_id::Id;
+
+use crate::error::Error;
+use crate::foundation::{id, NSInteger, NSString};
+
+/// A wrapper for `NSError`, as handed back from many AppKit/UIKit/Foundation calls.
+///
+/// This is useful when you need to inspect an error on the Objective-C side (or pass it back);
+/// for idiomatic Rust error handling, convert it via `into_rust_error()`.
+#[derive(Debug)]
+pub struct NSError(pub Id<Object>);
+
+impl NSError {
+    /// Given an `NSError` vended by the system, wraps and retains it. Equivalent to
+    /// `from_unretained()`.
+    pub fn retain(error: id) -> Self {
+        NSError::from_unretained(error)
+    }
+
+    /// Wraps an `NSError` that we don't own (e.g, one passed to a completion handler), retaining
+    /// it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(error: id) -> Self {
+        NSError(unsafe { Id::from_ptr(error) })
+    }
+
+    /// Wraps an `NSError` that we already own, taking over that ownership without an additional
+    /// retain.
+    pub fn from_retained(error: id) -> Self {
+        NSError(unsafe { Id::from_retained_ptr(error) })
+    }
+
+    /// Returns the domain of this error (e.g, `NSURLErrorDomain`).
+    pub fn domain(&self) -> String {
+        NSString::retain(unsafe { msg_send![&*self.0, domain] }).to_string()
+    }
+
+    /// Returns the error code, which is specific to the domain.
+    pub fn code(&self) -> i64 {
+        let code: NSInteger = unsafe { msg_send![&*self.0, code] };
+        code as i64
+    }
+
+    /// Returns the user-facing description of this error.
+    pub fn localized_description(&self) -> String {
+        NSString::retain(unsafe { msg_send![&*self.0, localizedDescription] }).to_string()
+    }
+
+    /// Converts this into a (thread safe) `Error::NSError`.
+    pub fn into_rust_error(self) -> Error {
+        Error::NSError {
+            code: self.code() as usize,
+            domain: self.domain(),
+            description: self.localized_description()
+        }
+    }
+}
+
+impl From<NSError> for Error {
+    fn from(error: NSError) -> Self {
+        error.into_rust_error()
+    }
+}
+
+impl Deref for NSError {
+    type Target = Object;
+
+    /// Derefs to the underlying Objective-C Object.
+    fn deref(&self) -> &Object {
+        &*self.0
+    }
+}
@@ -1,36 +1,53 @@
 use std::ffi::CStr;
+use std::fmt;
 use std::os::raw::c_char;
 
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
-use crate::foundation::{id, to_bool, NSInteger, BOOL, NO, YES};
+use crate::foundation::{id, to_bool, NSInteger, NSString, BOOL, NO, YES};
 
 /// Wrapper for a `NSNumber` object.
 ///
 /// In general we strive to avoid using this in the codebase, but it's a requirement for moving
 /// objects in and out of certain situations (e.g, `UserDefaults`).
-#[derive(Debug)]
 pub struct NSNumber(pub Id<Object>);
 
 impl NSNumber {
     /// If we're vended an NSNumber from a method (e.g, `NSUserDefaults` querying) we might want to
     /// wrap (and retain) it while we figure out what to do with it. This does that.
+    ///
+    /// Equivalent to `from_unretained()`.
     pub fn retain(data: id) -> Self {
-        NSNumber(unsafe { Id::from_ptr(data) })
+        NSNumber::from_unretained(data)
     }
 
     /// If we're vended an NSNumber from a method (e.g, `NSUserDefaults` querying) we might want to
     /// wrap it while we figure out what to do with it. This does that.
+    ///
+    /// Equivalent to `from_retained()`.
     pub fn wrap(data: id) -> Self {
+        NSNumber::from_retained(data)
+    }
+
+    /// Wraps an `NSNumber` that we don't own (e.g, one returned from a getter, or autoreleased),
+    /// retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(data: id) -> Self {
+        NSNumber(unsafe { Id::from_ptr(data) })
+    }
+
+    /// Wraps an `NSNumber` that we already own, taking over that ownership without an additional
+    /// retain.
+    pub fn from_retained(data: id) -> Self {
         NSNumber(unsafe { Id::from_retained_ptr(data) })
     }
 
     /// Constructs a `numberWithBool` instance of `NSNumber` and retains it.
     pub fn bool(value: bool) -> Self {
+        // The `numberWith...` constructors return autoreleased instances, so these need a retain.
         NSNumber(unsafe {
-            Id::from_retained_ptr(msg_send![class!(NSNumber), numberWithBool:match value {
+            Id::from_ptr(msg_send![class!(NSNumber), numberWithBool:match value {
                 true => YES,
                 false => NO
             }])
@@ -39,12 +56,12 @@ impl NSNumber {
 
     /// Constructs a `numberWithInteger` instance of `NSNumber` and retains it.
     pub fn integer(value: i64) -> Self {
-        NSNumber(unsafe { Id::from_retained_ptr(msg_send![class!(NSNumber), numberWithInteger: value as NSInteger]) })
+        NSNumber(unsafe { Id::from_ptr(msg_send![class!(NSNumber), numberWithInteger: value as NSInteger]) })
     }
 
     /// Constructs a `numberWithDouble` instance of `NSNumber` and retains it.
     pub fn float(value: f64) -> Self {
-        NSNumber(unsafe { Id::from_retained_ptr(msg_send![class!(NSNumber), numberWithDouble: value]) })
+        NSNumber(unsafe { Id::from_ptr(msg_send![class!(NSNumber), numberWithDouble: value]) })
     }
 
     /// Returns the `objCType` of the underlying `NSNumber` as a Rust `&str`. This flag can be used
@@ -97,6 +114,14 @@ impl NSNumber {
     }
 }
 
+impl fmt::Debug for NSNumber {
+    /// Formats as the `stringValue` of the underlying `NSNumber`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = NSString::retain(unsafe { msg_send![&*self.0, stringValue] });
+        write!(f, "NSNumber({})", value.to_str())
+    }
+}
+
 impl From<NSNumber> for id {
     /// Consumes and returns the underlying `NSNumber`.
     fn from(mut number: NSNumber) -> Self {
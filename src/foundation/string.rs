@@ -1,3 +1,4 @@
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::os::raw::c_char;
@@ -7,6 +8,7 @@ use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
+use crate::error::Error;
 use crate::foundation::{id, to_bool, BOOL, NO, YES};
 
 const UTF8_ENCODING: usize = 4;
@@ -38,6 +40,13 @@ impl<'a> NSString<'a> {
         }
     }
 
+    /// Creates a new `NSString` from UTF-16 code units, e.g as returned from Windows APIs or
+    /// JavaScript. Returns `Error::InvalidUtf8` if `units` isn't valid UTF-16.
+    pub fn from_utf16(units: &[u16]) -> Result<Self, Error> {
+        let s = String::from_utf16(units).map_err(|_| Error::InvalidUtf8)?;
+        Ok(NSString::new(&s))
+    }
+
     /// Creates a new `NSString` without copying the bytes for the passed-in string.
     pub fn no_copy(s: &'a str) -> Self {
         NSString {
@@ -55,15 +64,22 @@ impl<'a> NSString<'a> {
     }
 
     /// In cases where we're vended an `NSString` by the system, this can be used to wrap and
-    /// retain it.
+    /// retain it. Equivalent to `from_unretained()`.
     pub fn retain(object: id) -> Self {
+        NSString::from_unretained(object)
+    }
+
+    /// Wraps an `NSString` that we don't own (e.g, one returned from a getter, or autoreleased),
+    /// retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(object: id) -> Self {
         NSString {
             objc: unsafe { Id::from_ptr(object) },
             phantom: PhantomData
         }
     }
 
-    /// In some cases, we want to wrap a system-provided NSString without retaining it.
+    /// Wraps an `NSString` that we already own (e.g, from `alloc`/`init`, `new`, or `copy`),
+    /// taking over that ownership without an additional retain.
     pub fn from_retained(object: id) -> Self {
         NSString {
             objc: unsafe { Id::from_retained_ptr(object) },
@@ -71,6 +87,16 @@ impl<'a> NSString<'a> {
         }
     }
 
+    /// Retains and converts a system-provided `NSString` into an owned `String`, returning `None`
+    /// if the pointer is `nil`. This is useful for reading properties that are optional on the
+    /// Objective-C side (e.g, a title or URL that may not be set).
+    pub fn to_string_opt(object: id) -> Option<String> {
+        match object.is_null() {
+            true => None,
+            false => Some(NSString::retain(object).to_string())
+        }
+    }
+
     /// Utility method for checking whether an `NSObject` is an `NSString`.
     pub fn is(obj: id) -> bool {
         let result: BOOL = unsafe { msg_send![obj, isKindOfClass: class!(NSString)] };
@@ -90,8 +116,19 @@ impl<'a> NSString<'a> {
         unsafe { msg_send![&*self.objc, lengthOfBytesUsingEncoding: UTF8_ENCODING] }
     }
 
-    /// A utility method for taking an `NSString` and bridging it to a Rust `&str`.
-    pub fn to_str(&self) -> &str {
+    /// Borrows the contents of this `NSString` as a Rust `&str`, without copying them into a
+    /// `String`. The slice points into the buffer returned by `UTF8String`.
+    ///
+    /// That buffer isn't owned by `self`: Foundation may hand back autoreleased inner storage,
+    /// which is only valid until the enclosing autorelease pool drains - possibly sooner than
+    /// `self` is dropped. Don't hold the slice across a pool boundary (e.g, past the end of an
+    /// `autoreleasepool()` block, or a turn of the run loop); copy it with `to_string()` if you
+    /// need it longer. If the backing object is an `NSMutableString`, it must not be mutated while
+    /// the slice is held.
+    ///
+    /// The backing `NSString` must not be `nil`. If you're wrapping a pointer that might be, use
+    /// `NSString::to_string_opt()` instead.
+    pub fn as_str(&self) -> &str {
         let bytes = self.bytes();
         let len = self.bytes_len();
 
@@ -101,7 +138,14 @@ impl<'a> NSString<'a> {
         }
     }
 
+    /// An alias for `as_str()`; the same autorelease pool and mutation caveats apply.
+    pub fn to_str(&self) -> &str {
+        self.as_str()
+    }
+
     /// A utility method for taking an `NSString` and getting an owned `String` from it.
+    ///
+    /// As with `as_str()`, the backing `NSString` must not be `nil`.
     pub fn to_string(&self) -> String {
         self.to_str().to_string()
     }
@@ -113,6 +157,23 @@ impl fmt::Display for NSString<'_> {
     }
 }
 
+impl PartialEq for NSString<'_> {
+    /// Compares the UTF8 contents of both strings. Note that, like `str`, this does no Unicode
+    /// normalization - if you need Foundation's semantics, call through to `isEqualToString:`.
+    fn eq(&self, other: &Self) -> bool {
+        self.to_str() == other.to_str()
+    }
+}
+
+impl Eq for NSString<'_> {}
+
+impl Hash for NSString<'_> {
+    /// Hashes the UTF8 contents of the string, matching the `PartialEq` implementation.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_str().hash(state);
+    }
+}
+
 impl From<NSString<'_>> for id {
     /// Consumes and returns the pointer to the underlying NSString instance.
     fn from(mut string: NSString) -> Self {
@@ -1,13 +1,15 @@
 use std::error::Error;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
-use crate::foundation::{id, nil, NSData, NSString, NSUInteger};
+use crate::foundation::{id, nil, to_bool, NSData, NSString, NSUInteger, BOOL};
 
 mod bookmark_options;
 pub use bookmark_options::{NSURLBookmarkCreationOption, NSURLBookmarkResolutionOption};
@@ -37,15 +39,22 @@ pub struct NSURL<'a> {
 
 impl<'a> NSURL<'a> {
     /// In cases where we're vended an `NSURL` by the system, this can be used to wrap and
-    /// retain it.
+    /// retain it. Equivalent to `from_unretained()`.
     pub fn retain(object: id) -> Self {
+        NSURL::from_unretained(object)
+    }
+
+    /// Wraps an `NSURL` that we don't own (e.g, one returned from a getter, or autoreleased),
+    /// retaining it. The retain is balanced by a release when this is dropped.
+    pub fn from_unretained(object: id) -> Self {
         NSURL {
             objc: unsafe { ShareId::from_ptr(object) },
             phantom: PhantomData
         }
     }
 
-    /// In some cases, we want to wrap a system-provided NSURL without retaining it.
+    /// In some cases, we want to wrap a system-provided NSURL without retaining it. This takes
+    /// over ownership of an already-retained `NSURL`.
     pub fn from_retained(object: id) -> Self {
         NSURL {
             objc: unsafe { ShareId::from_retained_ptr(object) },
@@ -54,6 +63,9 @@ impl<'a> NSURL<'a> {
     }
 
     /// Creates and returns a URL object by calling through to `[NSURL URLWithString]`.
+    ///
+    /// This will panic if `url` can't be parsed; use `NSURL::from_str()` (or `url.parse()`) if
+    /// you're not sure the input is valid.
     pub fn with_str(url: &str) -> Self {
         let url = NSString::new(url);
 
@@ -165,6 +177,41 @@ impl<'a> NSURL<'a> {
     }
 }*/
 
+impl FromStr for NSURL<'_> {
+    type Err = crate::error::Error;
+
+    /// Creates a URL via `[NSURL URLWithString]`, returning `Error::InvalidUrl` if it can't be
+    /// parsed.
+    fn from_str(url: &str) -> Result<Self, Self::Err> {
+        let s = NSString::new(url);
+        let object: id = unsafe { msg_send![class!(NSURL), URLWithString:&*s] };
+
+        match object.is_null() {
+            true => Err(crate::error::Error::InvalidUrl(url.to_string())),
+            false => Ok(NSURL::from_unretained(object))
+        }
+    }
+}
+
+impl PartialEq for NSURL<'_> {
+    /// Compares both URLs via `isEqual:`, which requires the base URL and string to match.
+    fn eq(&self, other: &Self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, isEqual:&*other.objc] };
+        to_bool(result)
+    }
+}
+
+impl Eq for NSURL<'_> {}
+
+impl Hash for NSURL<'_> {
+    /// Feeds the Foundation-provided `hash` into `state`. Foundation guarantees that equal URLs
+    /// produce equal hashes.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash: NSUInteger = unsafe { msg_send![&*self.objc, hash] };
+        hash.hash(state);
+    }
+}
+
 impl Deref for NSURL<'_> {
     type Target = Object;
 
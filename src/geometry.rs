@@ -1,10 +1,10 @@
-//! Wrapper methods for various geometry types (rects, sizes, ec).
+//! Wrapper methods for various geometry types (rects, points, sizes, etc).
 
 use core_graphics::geometry::{CGPoint, CGRect, CGSize};
 
 /// A struct that represents a box - top, left, width and height. You might use this for, say,
 /// setting the initial frame of a view.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct Rect {
     /// Distance from the top, in points.
     pub top: f64,
@@ -39,6 +39,16 @@ impl Rect {
             height: 0.0
         }
     }
+
+    /// Returns the origin (`left`, `top`) of this Rect as a `Point`.
+    pub fn origin(&self) -> Point {
+        Point::new(self.left, self.top)
+    }
+
+    /// Returns the `width` and `height` of this Rect as a `Size`.
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
 }
 
 impl From<Rect> for CGRect {
@@ -57,3 +67,79 @@ impl From<CGRect> for Rect {
         }
     }
 }
+
+/// A struct that represents a point in a coordinate space - e.g, a mouse location, or the center
+/// of a magnification.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Point {
+    /// Position along the x-axis, in points.
+    pub x: f64,
+
+    /// Position along the y-axis, in points.
+    pub y: f64
+}
+
+impl Point {
+    /// Returns a new `Point` initialized with the values specified.
+    pub fn new(x: f64, y: f64) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns a zero'd out Point.
+    pub fn zero() -> Self {
+        Point { x: 0.0, y: 0.0 }
+    }
+}
+
+impl From<Point> for CGPoint {
+    fn from(point: Point) -> CGPoint {
+        CGPoint::new(point.x, point.y)
+    }
+}
+
+impl From<CGPoint> for Point {
+    fn from(point: CGPoint) -> Point {
+        Point {
+            x: point.x as f64,
+            y: point.y as f64
+        }
+    }
+}
+
+/// A struct that represents a size - width and height. You might use this for, say, an intrinsic
+/// content size.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Size {
+    /// Width, in points.
+    pub width: f64,
+
+    /// Height, in points.
+    pub height: f64
+}
+
+impl Size {
+    /// Returns a new `Size` initialized with the values specified.
+    pub fn new(width: f64, height: f64) -> Self {
+        Size { width, height }
+    }
+
+    /// Returns a zero'd out Size.
+    pub fn zero() -> Self {
+        Size { width: 0.0, height: 0.0 }
+    }
+}
+
+impl From<Size> for CGSize {
+    fn from(size: Size) -> CGSize {
+        CGSize::new(size.width, size.height)
+    }
+}
+
+impl From<CGSize> for Size {
+    fn from(size: CGSize) -> Size {
+        Size {
+            width: size.width as f64,
+            height: size.height as f64
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::foundation::NSUInteger;
+
+/// Specifies how an `ImageView` should scale its image to fit its bounds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageScaling {
+    /// Scale the image down to fit, preserving the aspect ratio. Images smaller than the view are
+    /// left as-is. This is the default.
+    ProportionallyDown,
+
+    /// Scale each dimension to fit exactly, ignoring the aspect ratio.
+    AxesIndependently,
+
+    /// Don't scale the image at all.
+    None,
+
+    /// Scale the image up or down to fit, preserving the aspect ratio.
+    ProportionallyUpOrDown
+}
+
+impl From<ImageScaling> for NSUInteger {
+    fn from(scaling: ImageScaling) -> Self {
+        match scaling {
+            ImageScaling::ProportionallyDown => 0,
+            ImageScaling::AxesIndependently => 1,
+            ImageScaling::None => 2,
+            ImageScaling::ProportionallyUpOrDown => 3
+        }
+    }
+}
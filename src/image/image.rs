@@ -12,7 +12,8 @@ use core_graphics::{
 };
 
 use super::icons::*;
-use crate::foundation::{id, NSData, NSString, NO, YES};
+use crate::error::Error;
+use crate::foundation::{id, nil, NSData, NSString, NO, YES};
 use crate::utils::os;
 
 /// Specifies resizing behavior for image drawing.
@@ -136,6 +137,21 @@ impl Image {
         Image(unsafe { ShareId::from_ptr(image) })
     }
 
+    /// Returns a `CGImageRef` for this image, e.g for setting as a layer's contents. On macOS,
+    /// this picks the best representation for the image's size.
+    ///
+    /// The returned pointer is not retained, and is only guaranteed to live as long as this
+    /// `Image` does - whatever it's handed to (e.g, a `CALayer`) should retain it.
+    pub fn cg_image(&self) -> id {
+        unsafe {
+            #[cfg(feature = "appkit")]
+            return msg_send![&*self.0, CGImageForProposedRect:nil context:nil hints:nil];
+
+            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            return msg_send![&*self.0, CGImage];
+        }
+    }
+
     /// Loads an image from the specified path.
     pub fn with_contents_of_file(path: &str) -> Self {
         let file_path = NSString::new(path);
@@ -146,15 +162,40 @@ impl Image {
         })
     }
 
+    /// Loads an image by name, searching the main bundle's resources (and, on macOS, named system
+    /// images such as `NSImageNameFolder`). This uses `imageNamed:`, so the system caches the
+    /// result.
+    ///
+    /// Returns `Error::NilPointer` if no image with that name can be found.
+    pub fn named(name: &str) -> Result<Self, Error> {
+        let name = NSString::new(name);
+
+        unsafe {
+            let image: id = msg_send![Self::class(), imageNamed:&*name];
+
+            match image.is_null() {
+                true => Err(Error::NilPointer),
+                false => Ok(Image(ShareId::from_ptr(image)))
+            }
+        }
+    }
+
     /// Given a Vec of data, will transform it into an Image by passing it through NSData.
     /// This can be useful for when you need to include_bytes!() something into your binary.
-    pub fn with_data(data: &[u8]) -> Self {
+    ///
+    /// Returns `Error::NilPointer` if the data can't be decoded as an image.
+    pub fn with_data(data: &[u8]) -> Result<Self, Error> {
         let data = NSData::with_slice(data);
 
-        Image(unsafe {
+        unsafe {
             let alloc: id = msg_send![Self::class(), alloc];
-            ShareId::from_ptr(msg_send![alloc, initWithData: data])
-        })
+            let image: id = msg_send![alloc, initWithData:&*data];
+
+            match image.is_null() {
+                true => Err(Error::NilPointer),
+                false => Ok(Image(ShareId::from_retained_ptr(image)))
+            }
+        }
     }
 
     // @TODO: for Airyx, unsure if this is supported - and it's somewhat modern macOS-specific, so
@@ -289,7 +330,7 @@ impl Image {
 #[test]
 fn test_image_from_bytes() {
     let image_bytes = include_bytes!("../../test-data/favicon.ico");
-    let image = Image::with_data(image_bytes);
+    let image = Image::with_data(image_bytes).unwrap();
 }
 // It's unclear where the file is on the ios simulator.
 #[test]
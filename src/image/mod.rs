@@ -3,7 +3,7 @@ use objc::{msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
 use crate::color::Color;
-use crate::foundation::{id, nil, NSArray, NSString, NO, YES};
+use crate::foundation::{id, nil, NSArray, NSString, NSUInteger, NO, YES};
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
@@ -29,6 +29,12 @@ pub use image::{DrawConfig, Image, ResizeBehavior};
 mod icons;
 pub use icons::*;
 
+#[cfg(feature = "appkit")]
+mod enums;
+
+#[cfg(feature = "appkit")]
+pub use enums::ImageScaling;
+
 /// A helper method for instantiating view classes and applying default settings to them.
 fn allocate_view(registration_fn: fn() -> *const Class) -> id {
     unsafe {
@@ -156,12 +162,36 @@ impl ImageView {
         });
     }
 
-    /*pub fn set_image_scaling(&self, scaling_type: ImageScale) {
+    /// Removes any image currently set on this image view.
+    pub fn clear_image(&self) {
         self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setImage: nil];
+        });
+    }
 
-            let _: () = msg_send![obj, setImageScaling:
+    /// Sets how the image should be scaled to fit the bounds of this view.
+    #[cfg(feature = "appkit")]
+    pub fn set_image_scaling(&self, scaling: ImageScaling) {
+        let scaling: NSUInteger = scaling.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setImageScaling: scaling];
         });
-    }*/
+    }
+
+    /// Sets the color used to tint template images (e.g, SF Symbols). Non-template images are
+    /// drawn as-is.
+    pub fn set_content_tint_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "appkit")]
+            let _: () = msg_send![obj, setContentTintColor: color];
+
+            #[cfg(feature = "uikit")]
+            let _: () = msg_send![obj, setTintColor: color];
+        });
+    }
 }
 
 impl ObjcAccess for ImageView {
@@ -200,6 +230,6 @@ fn test_image() {
     let image_view = ImageView::new();
     image_view.set_background_color(Color::SystemBlue);
     let image_bytes = include_bytes!("../../test-data/favicon.ico");
-    let image = Image::with_data(image_bytes);
+    let image = Image::with_data(image_bytes).unwrap();
     image_view.set_image(&image);
 }
@@ -6,8 +6,8 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
 use crate::dragdrop::DragInfo;
-use crate::foundation::{id, load_or_register_class, NSString, NSUInteger, NO, YES};
-use crate::input::{TextFieldDelegate, TEXTFIELD_DELEGATE_PTR};
+use crate::foundation::{id, load_or_register_class, DelegateContainer, NSString, NSUInteger, NO, YES};
+use crate::input::{TextFieldDelegate, TextHandler, TEXTFIELD_CHANGE_HANDLER, TEXTFIELD_DELEGATE_PTR, TEXTFIELD_SUBMIT_HANDLER};
 use crate::utils::load;
 
 /// Called when editing this text field has ended (e.g. user pressed enter).
@@ -23,7 +23,13 @@ extern "C" fn text_did_begin_editing<T: TextFieldDelegate>(this: &mut Object, _:
     view.text_did_begin_editing(s.to_str());
 }
 
-extern "C" fn text_did_change<T: TextFieldDelegate>(this: &mut Object, _: Sel, _info: id) {
+extern "C" fn text_did_change<T: TextFieldDelegate>(this: &mut Object, _: Sel, info: id) {
+    // NSTextField relays this to its delegate as `controlTextDidChange:`, which `on_change()`
+    // relies on - so we need to make sure it still gets there.
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSTextField)), textDidChange: info];
+    }
+
     let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
     let s = NSString::retain(unsafe { msg_send![this, stringValue] });
     view.text_did_change(s.to_str());
@@ -48,6 +54,55 @@ extern "C" fn text_should_end_editing<T: TextFieldDelegate>(this: &mut Object, _
     }
 }
 
+/// Fires the `on_change()` handler, if one is set. Every field is its own delegate, so this is
+/// called as part of `NSControlTextEditingDelegate`.
+extern "C" fn control_text_did_change(this: &Object, _: Sel, _notification: id) {
+    if let Some(handler) = unsafe { DelegateContainer::<TextHandler>::get(this, TEXTFIELD_CHANGE_HANDLER) } {
+        let s = NSString::retain(unsafe { msg_send![this, stringValue] });
+        handler(s.to_string());
+    }
+}
+
+/// Fires the `on_submit()` handler, if one is set, when the user presses Enter. We return `NO`
+/// regardless, so that the field editor still handles the command as it normally would (e.g,
+/// ending editing and sending the action).
+extern "C" fn control_do_command_by_selector(this: &Object, _: Sel, _control: id, text_view: id, command: Sel) -> BOOL {
+    if command == sel!(insertNewline:) {
+        if let Some(handler) = unsafe { DelegateContainer::<TextHandler>::get(this, TEXTFIELD_SUBMIT_HANDLER) } {
+            let s = NSString::retain(unsafe { msg_send![text_view, string] });
+            handler(s.to_string());
+        }
+    }
+
+    NO
+}
+
+/// Drops any Rust-side handlers we're holding.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        DelegateContainer::<TextHandler>::clear(this, TEXTFIELD_CHANGE_HANDLER);
+        DelegateContainer::<TextHandler>::clear(this, TEXTFIELD_SUBMIT_HANDLER);
+
+        let _: () = msg_send![super(this, class!(NSTextField)), dealloc];
+    }
+}
+
+/// Registers the ivars and methods needed for `on_change()` and `on_submit()` support.
+unsafe fn add_text_handlers(decl: &mut ClassDecl) {
+    decl.add_ivar::<usize>(TEXTFIELD_CHANGE_HANDLER);
+    decl.add_ivar::<usize>(TEXTFIELD_SUBMIT_HANDLER);
+
+    decl.add_method(
+        sel!(controlTextDidChange:),
+        control_text_did_change as extern "C" fn(&Object, _, _)
+    );
+    decl.add_method(
+        sel!(control:textView:doCommandBySelector:),
+        control_do_command_by_selector as extern "C" fn(&Object, _, id, id, Sel) -> BOOL
+    );
+    decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, _));
+}
+
 /// Injects an `NSTextField` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
@@ -57,7 +112,8 @@ pub(crate) fn register_view_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSTextField);
-        let decl = ClassDecl::new("RSTTextInputField", superclass).unwrap();
+        let mut decl = ClassDecl::new("RSTTextInputField", superclass).unwrap();
+        add_text_handlers(&mut decl);
         VIEW_CLASS = decl.register();
     });
 
@@ -71,6 +127,7 @@ pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>(instance:
         // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
         // move.
         decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);
+        add_text_handlers(decl);
 
         decl.add_method(
             sel!(textDidEndEditing:),
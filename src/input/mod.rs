@@ -43,13 +43,15 @@
 //!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
+use std::cell::Cell;
+
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
 use crate::color::Color;
 use crate::control::Control;
-use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NO, YES};
+use crate::foundation::{id, nil, DelegateContainer, NSArray, NSInteger, NSString, NO, YES};
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::text::{Font, TextAlign};
@@ -74,6 +76,11 @@ mod traits;
 pub use traits::TextFieldDelegate;
 
 pub(crate) static TEXTFIELD_DELEGATE_PTR: &str = "rstTextFieldDelegatePtr";
+pub(crate) static TEXTFIELD_CHANGE_HANDLER: &str = "rstTextFieldChangeHandler";
+pub(crate) static TEXTFIELD_SUBMIT_HANDLER: &str = "rstTextFieldSubmitHandler";
+
+/// A boxed closure that receives the current text of a field.
+pub(crate) type TextHandler = Box<dyn Fn(String) + 'static>;
 
 /// A helper method for instantiating view classes and applying default settings to them.
 fn common_init(class: *const Class) -> id {
@@ -86,6 +93,11 @@ fn common_init(class: *const Class) -> id {
         #[cfg(feature = "appkit")]
         let _: () = msg_send![view, setWantsLayer: YES];
 
+        // The field acts as its own `NSTextFieldDelegate`, which lets us route `on_change()` and
+        // `on_submit()` without another object to keep alive. This is a weak reference.
+        #[cfg(feature = "appkit")]
+        let _: () = msg_send![view, setDelegate: view];
+
         view
     }
 }
@@ -295,6 +307,32 @@ impl<T> TextField<T> {
             .get(|obj| unsafe { NSString::retain(msg_send![obj, stringValue]).to_string() })
     }
 
+    /// Calls `handler` with the current text whenever the user edits this field. Calling this again
+    /// replaces any existing handler.
+    #[cfg(feature = "appkit")]
+    pub fn on_change<F: Fn(String) + 'static>(&self, handler: F) {
+        self.set_text_handler(TEXTFIELD_CHANGE_HANDLER, handler);
+    }
+
+    /// Calls `handler` with the current text whenever the user presses Enter in this field. Calling
+    /// this again replaces any existing handler.
+    #[cfg(feature = "appkit")]
+    pub fn on_submit<F: Fn(String) + 'static>(&self, handler: F) {
+        self.set_text_handler(TEXTFIELD_SUBMIT_HANDLER, handler);
+    }
+
+    /// Stores `handler` in the given ivar, dropping any existing one.
+    #[cfg(feature = "appkit")]
+    fn set_text_handler<F: Fn(String) + 'static>(&self, ivar: &'static str, handler: F) {
+        let handler: Cell<Option<TextHandler>> = Cell::new(Some(Box::new(handler)));
+
+        self.objc.with_mut(|obj| unsafe {
+            if let Some(handler) = handler.take() {
+                DelegateContainer::set(&mut *obj, ivar, handler);
+            }
+        });
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         self.objc.with_mut(|obj| unsafe {
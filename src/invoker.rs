@@ -9,16 +9,12 @@
 //! is going away.
 
 use std::fmt;
-use std::sync::{Arc, Mutex, Once};
 
-use block::{Block, ConcreteBlock, RcBlock};
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
-use crate::foundation::{id, nil, NSString};
-use crate::utils::load;
+use crate::foundation::{id, load_or_register_class, DelegateContainer};
 
 pub static ACTION_CALLBACK_PTR: &str = "rstTargetActionPtr";
 
@@ -30,7 +26,7 @@ pub static ACTION_CALLBACK_PTR: &str = "rstTargetActionPtr";
 /// Point is, Button aren't created that much in the grand scheme of things,
 /// and the heap isn't our enemy in a GUI framework anyway. If someone knows
 /// a better way to do this that doesn't require double-boxing, I'm all ears.
-pub struct Action(Box<dyn Fn() + Send + Sync + 'static>);
+pub struct Action(Box<dyn Fn() + 'static>);
 
 impl fmt::Debug for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -46,40 +42,47 @@ impl fmt::Debug for Action {
 ///
 /// Widgets that use this should keep it around; on drop,
 /// it _will_ remove your events somewhat transparently per Cocoa rules.
+///
+/// The callback itself is owned by the invoker object, and is released when
+/// the invoker deallocates - i.e, when this handler (and anything else retaining
+/// the invoker) goes away.
 #[derive(Debug)]
 pub struct TargetActionHandler {
-    action: Box<Action>,
     invoker: ShareId<Object>
 }
 
 impl TargetActionHandler {
     /// Returns a new TargetEventHandler.
-    pub fn new<F: Fn() + Send + Sync + 'static>(control: &Object, action: F) -> Self {
-        let block = Box::new(Action(Box::new(action)));
-        let ptr = Box::into_raw(block);
-
+    pub fn new<F: Fn() + 'static>(control: &Object, action: F) -> Self {
         let invoker = unsafe {
-            ShareId::from_ptr({
-                let invoker: id = msg_send![register_invoker_class::<F>(), alloc];
+            ShareId::from_retained_ptr({
+                let invoker: id = msg_send![register_invoker_class(), alloc];
                 let invoker: id = msg_send![invoker, init];
-                (&mut *invoker).set_ivar(ACTION_CALLBACK_PTR, ptr as usize);
+                DelegateContainer::set(&mut *invoker, ACTION_CALLBACK_PTR, Action(Box::new(action)));
                 let _: () = msg_send![control, setAction: sel!(perform:)];
                 let _: () = msg_send![control, setTarget: invoker];
                 invoker
             })
         };
 
-        TargetActionHandler {
-            invoker: invoker,
-            action: unsafe { Box::from_raw(ptr) }
-        }
+        TargetActionHandler { invoker }
     }
 }
 
 /// This will fire for an NSButton callback.
-extern "C" fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id) {
-    let action = load::<Action>(this, ACTION_CALLBACK_PTR);
-    (action.0)();
+extern "C" fn perform(this: &mut Object, _: Sel, _sender: id) {
+    if let Some(action) = unsafe { DelegateContainer::<Action>::get(this, ACTION_CALLBACK_PTR) } {
+        (action.0)();
+    }
+}
+
+/// Releases the callback we're holding.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        DelegateContainer::<Action>::clear(this, ACTION_CALLBACK_PTR);
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
 }
 
 /// Due to the way that Rust and Objective-C live... very different lifestyles,
@@ -91,22 +94,12 @@ extern "C" fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id)
 /// generic block over. It's still Rust, so you can't do crazy callbacks, but
 /// you can at least fire an event off and do something.
 ///
-/// The `NSButton` owns this object on instantiation, and will release it
-/// on drop. We handle the heap copy on the Rust side, so setting the block
-/// is just an ivar.
-pub(crate) fn register_invoker_class<F: Fn() + 'static>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSObject);
-        let mut decl = ClassDecl::new("RSTTargetActionHandler", superclass).unwrap();
-
+/// Controls hold their target weakly, so the `TargetActionHandler` owns this
+/// object; the callback is dropped when it deallocates.
+pub(crate) fn register_invoker_class() -> *const Class {
+    load_or_register_class("NSObject", "RSTTargetActionHandler", |decl| unsafe {
         decl.add_ivar::<usize>(ACTION_CALLBACK_PTR);
-        decl.add_method(sel!(perform:), perform::<F> as extern "C" fn(&mut Object, _, id));
-
-        VIEW_CLASS = decl.register();
-    });
-
-    unsafe { VIEW_CLASS }
+        decl.add_method(sel!(perform:), perform as extern "C" fn(&mut Object, _, id));
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, _));
+    })
 }
@@ -16,8 +16,71 @@ use core_graphics::base::CGFloat;
 
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::id;
+use crate::color::Color;
+use crate::foundation::{id, NSString};
+use crate::image::Image;
 use crate::utils::properties::ObjcProperty;
+use crate::utils::CGSize;
+
+/// Describes how a layer's `contents` are positioned or scaled within its bounds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Gravity {
+    /// Centered, without scaling.
+    Center,
+
+    /// Centered along the top edge, without scaling.
+    Top,
+
+    /// Centered along the bottom edge, without scaling.
+    Bottom,
+
+    /// Centered along the left edge, without scaling.
+    Left,
+
+    /// Centered along the right edge, without scaling.
+    Right,
+
+    /// In the top-left corner, without scaling.
+    TopLeft,
+
+    /// In the top-right corner, without scaling.
+    TopRight,
+
+    /// In the bottom-left corner, without scaling.
+    BottomLeft,
+
+    /// In the bottom-right corner, without scaling.
+    BottomRight,
+
+    /// Stretched to fill the bounds, ignoring the aspect ratio. This is the default.
+    Resize,
+
+    /// Scaled to fit within the bounds, preserving the aspect ratio.
+    ResizeAspect,
+
+    /// Scaled to fill the bounds, preserving the aspect ratio; the contents may be clipped.
+    ResizeAspectFill
+}
+
+impl From<Gravity> for NSString<'static> {
+    fn from(gravity: Gravity) -> Self {
+        // These are the values of the `kCAGravity*` constants.
+        NSString::no_copy(match gravity {
+            Gravity::Center => "center",
+            Gravity::Top => "top",
+            Gravity::Bottom => "bottom",
+            Gravity::Left => "left",
+            Gravity::Right => "right",
+            Gravity::TopLeft => "topLeft",
+            Gravity::TopRight => "topRight",
+            Gravity::BottomLeft => "bottomLeft",
+            Gravity::BottomRight => "bottomRight",
+            Gravity::Resize => "resize",
+            Gravity::ResizeAspect => "resizeAspect",
+            Gravity::ResizeAspectFill => "resizeAspectFill"
+        })
+    }
+}
 
 /// Represents a `CALayer`.
 ///
@@ -42,7 +105,7 @@ impl Layer {
     /// Creates a new `CALayer` and retains it.
     pub fn new() -> Self {
         Layer {
-            objc: ObjcProperty::retain(unsafe { msg_send![class!(CALayer), new] })
+            objc: ObjcProperty::from_retained(unsafe { msg_send![class!(CALayer), new] })
         }
     }
 
@@ -53,6 +116,14 @@ impl Layer {
         }
     }
 
+    /// Wraps an existing `CALayer` that we don't own (e.g, the result of `[view layer]`),
+    /// retaining it.
+    pub fn from_unretained(layer: id) -> Self {
+        Layer {
+            objc: ObjcProperty::from_unretained(layer)
+        }
+    }
+
     /// Sets the corner radius (for all four corners).
     ///
     /// Note that for performance sensitive contexts, you might want to apply a mask instead.
@@ -61,4 +132,62 @@ impl Layer {
             let _: () = msg_send![obj, setCornerRadius: radius as CGFloat];
         });
     }
+
+    /// Sets the width of the border drawn around this layer. Defaults to `0.0` (no border).
+    pub fn set_border_width(&self, width: f64) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBorderWidth: width as CGFloat];
+        });
+    }
+
+    /// Sets the color of the border drawn around this layer. See `set_border_width()`.
+    ///
+    /// Note that this is resolved to a `CGColor` at call time, so dynamic colors won't update
+    /// when the system appearance changes.
+    pub fn set_border_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let cg: id = msg_send![color, CGColor];
+            let _: () = msg_send![obj, setBorderColor: cg];
+        });
+    }
+
+    /// Configures the drop shadow for this layer. `opacity` ranges from `0.0` (the default, i.e
+    /// no shadow) to `1.0`, and `offset` is `(x, y)` in points.
+    ///
+    /// Shadows are drawn outside of the layer's bounds, so they won't be visible if the layer
+    /// clips to its bounds (`masksToBounds`). As with `set_border_color()`, the color is resolved
+    /// at call time.
+    pub fn set_shadow<C: AsRef<Color>>(&self, radius: f64, opacity: f32, offset: (f64, f64), color: C) {
+        let color: id = color.as_ref().into();
+        let offset = CGSize::new(offset.0 as CGFloat, offset.1 as CGFloat);
+
+        self.objc.with_mut(|obj| unsafe {
+            let cg: id = msg_send![color, CGColor];
+            let _: () = msg_send![obj, setShadowColor: cg];
+            let _: () = msg_send![obj, setShadowRadius: radius as CGFloat];
+            let _: () = msg_send![obj, setShadowOpacity: opacity];
+            let _: () = msg_send![obj, setShadowOffset: offset];
+        });
+    }
+
+    /// Sets the contents of this layer to the given image. This is a much cheaper way to display
+    /// a static (e.g, decorative) image than an `ImageView`, as no extra view is involved.
+    pub fn set_contents(&self, image: &Image) {
+        let contents = image.cg_image();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setContents: contents];
+        });
+    }
+
+    /// Sets how the contents of this layer are positioned or scaled within its bounds.
+    pub fn set_contents_gravity(&self, gravity: Gravity) {
+        let gravity: NSString = gravity.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setContentsGravity:&*gravity];
+        });
+    }
 }
@@ -206,7 +206,7 @@ impl From<NSUInteger> for LayoutFormat {
 }
 
 /// Specifies layout priority.
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum LayoutPriority {
     /// Highest priority.
     Required,
@@ -215,5 +215,19 @@ pub enum LayoutPriority {
     High,
 
     /// Low priority.
-    Low
+    Low,
+
+    /// A custom priority, from `1.0` to `1000.0` (`Required`).
+    Custom(f32)
+}
+
+impl From<LayoutPriority> for f32 {
+    fn from(priority: LayoutPriority) -> Self {
+        match priority {
+            LayoutPriority::Required => 1000.,
+            LayoutPriority::High => 750.,
+            LayoutPriority::Low => 250.,
+            LayoutPriority::Custom(priority) => priority
+        }
+    }
 }
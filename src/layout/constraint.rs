@@ -8,7 +8,8 @@ use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
-use crate::foundation::{id, NO, YES};
+use crate::foundation::{id, NSArray, NSInteger, NO, YES};
+use crate::layout::LayoutPriority;
 
 #[cfg(all(feature = "appkit", target_os = "macos"))]
 use super::LayoutConstraintAnimatorProxy;
@@ -40,14 +41,21 @@ pub struct LayoutConstraint {
 impl LayoutConstraint {
     /// An internal method for wrapping existing constraints.
     pub(crate) fn new(object: id) -> Self {
+        let (offset, multiplier, priority) = unsafe {
+            let offset: CGFloat = msg_send![object, constant];
+            let multiplier: CGFloat = msg_send![object, multiplier];
+            let priority: f32 = msg_send![object, priority];
+            (offset as f64, multiplier as f64, priority as f64)
+        };
+
         LayoutConstraint {
             #[cfg(all(feature = "appkit", target_os = "macos"))]
             animator: LayoutConstraintAnimatorProxy::new(object),
 
             constraint: unsafe { ShareId::from_ptr(object) },
-            offset: 0.0,
-            multiplier: 0.0,
-            priority: 0.0
+            offset,
+            multiplier,
+            priority
         }
     }
 
@@ -80,9 +88,66 @@ impl LayoutConstraint {
         }
     }
 
-    /// Set whether this constraint is active or not. If you're doing this across a batch of
-    /// constraints, it's often more performant to batch-deactivate with
-    /// `LayoutConstraint::deactivate()`.
+    /// Sets the multiplier for this constraint - e.g, to make one view half the width of another.
+    ///
+    /// `NSLayoutConstraint` doesn't allow changing the multiplier after creation, so this returns
+    /// a new (inactive) constraint with the same anchors, offset, and priority. Call this before
+    /// activating the constraint.
+    pub fn multiplier<F: Into<f64>>(self, multiplier: F) -> Self {
+        let multiplier: f64 = multiplier.into();
+
+        let constraint: id = unsafe {
+            let c = &*self.constraint;
+            let first_item: id = msg_send![c, firstItem];
+            let first_attribute: NSInteger = msg_send![c, firstAttribute];
+            let relation: NSInteger = msg_send![c, relation];
+            let second_item: id = msg_send![c, secondItem];
+            let second_attribute: NSInteger = msg_send![c, secondAttribute];
+            let constant: CGFloat = msg_send![c, constant];
+            let priority: f32 = msg_send![c, priority];
+
+            let constraint: id = msg_send![class!(NSLayoutConstraint), constraintWithItem:first_item
+                attribute:first_attribute
+                relatedBy:relation
+                toItem:second_item
+                attribute:second_attribute
+                multiplier:multiplier as CGFloat
+                constant:constant
+            ];
+
+            let _: () = msg_send![constraint, setPriority: priority];
+            constraint
+        };
+
+        LayoutConstraint::new(constraint)
+    }
+
+    /// Sets the priority for this constraint, which determines which constraints are broken
+    /// first when they can't all be satisfied.
+    ///
+    /// Note that AppKit and UIKit don't allow changing a constraint to or from `Required` once
+    /// it's been activated; set this before activating.
+    pub fn priority(self, priority: LayoutPriority) -> Self {
+        self.set_priority(priority);
+
+        LayoutConstraint {
+            priority: f32::from(priority) as f64,
+            ..self
+        }
+    }
+
+    /// Sets the priority of a borrowed constraint. See `priority()`.
+    pub fn set_priority(&self, priority: LayoutPriority) {
+        let priority: f32 = priority.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.constraint, setPriority: priority];
+        }
+    }
+
+    /// Set whether this constraint is active or not - e.g, `set_active(true)` to activate a single
+    /// constraint. If you're doing this across a batch of constraints, it's often more performant
+    /// to batch-(de)activate with `LayoutConstraint::activate()`/`LayoutConstraint::deactivate()`.
     pub fn set_active(&self, active: bool) {
         unsafe {
             let _: () = msg_send![&*self.constraint, setActive:match active {
@@ -102,17 +167,31 @@ impl LayoutConstraint {
     // I regret nothing, lol. If you have a better solution I'm all ears.
     pub fn activate(constraints: &[LayoutConstraint]) {
         let ids: Vec<&Object> = constraints.into_iter().map(|constraint| &*constraint.constraint).collect();
+        let constraints: NSArray = ids.into();
+
         unsafe {
-            let constraints: id = msg_send![class!(NSArray), arrayWithObjects:ids.as_ptr() count:ids.len()];
-            let _: () = msg_send![class!(NSLayoutConstraint), activateConstraints: constraints];
+            let _: () = msg_send![class!(NSLayoutConstraint), activateConstraints:&*constraints];
         }
     }
 
+    /// Call this with your batch of constraints to deactivate them.
     pub fn deactivate(constraints: &[LayoutConstraint]) {
         let ids: Vec<&Object> = constraints.into_iter().map(|constraint| &*constraint.constraint).collect();
+        let constraints: NSArray = ids.into();
+
         unsafe {
-            let constraints: id = msg_send![class!(NSArray), arrayWithObjects:ids.as_ptr() count:ids.len()];
-            let _: () = msg_send![class!(NSLayoutConstraint), deactivateConstraints: constraints];
+            let _: () = msg_send![class!(NSLayoutConstraint), deactivateConstraints:&*constraints];
         }
     }
 }
+
+/// Activates a batch of constraints in one go, which is considerably cheaper than activating them
+/// one at a time. Equivalent to `LayoutConstraint::activate()`.
+pub fn activate(constraints: &[LayoutConstraint]) {
+    LayoutConstraint::activate(constraints);
+}
+
+/// Deactivates a batch of constraints in one go. Equivalent to `LayoutConstraint::deactivate()`.
+pub fn deactivate(constraints: &[LayoutConstraint]) {
+    LayoutConstraint::deactivate(constraints);
+}
@@ -22,7 +22,7 @@ pub use attributes::*;
 mod constraint;
 
 #[cfg(feature = "autolayout")]
-pub use constraint::LayoutConstraint;
+pub use constraint::{activate, deactivate, LayoutConstraint};
 
 #[cfg(feature = "autolayout")]
 mod dimension;
@@ -5,9 +5,12 @@ use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 use crate::utils::os;
 
 /// A SafeAreaLayoutGuide should exist on all view types, and ensures that there are anchor points
-/// that work within the system constraints. On macOS 11+, this will ensure you work around system
-/// padding transprently - on macOS 10.15 and under, this will transparently map to the normal
-/// edges, as the underlying properties were not supported there.
+/// that work within the system constraints - e.g, keeping content clear of the title bar (and its
+/// traffic light buttons) in a window with a full size content view.
+///
+/// This is backed by `safeAreaLayoutGuide`, which requires macOS 11 (iOS 11). On older systems,
+/// these anchors transparently map to the view's normal edges, as the underlying property isn't
+/// supported there - so content _can_ end up underneath the title bar on macOS 10.15 and below.
 #[derive(Clone, Debug)]
 pub struct SafeAreaLayoutGuide {
     /// A pointer to the Objective-C runtime top layout constraint.
@@ -47,7 +50,7 @@ impl SafeAreaLayoutGuide {
     pub fn new(view: id) -> Self {
         // For versions prior to Big Sur, we'll just use the default view anchors in place.
         let guide: id = match os::is_minimum_version(11) {
-            true => unsafe { msg_send![view, layoutMarginsGuide] },
+            true => unsafe { msg_send![view, safeAreaLayoutGuide] },
             false => view
         };
 
@@ -8,8 +8,9 @@ use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
+use crate::color::Color;
 use crate::foundation::{id, nil, to_bool, NSArray, NSString, NO, YES};
-use crate::geometry::Rect;
+use crate::geometry::{Point, Rect};
 use crate::objc_access::ObjcAccess;
 
 #[cfg(feature = "appkit")]
@@ -32,6 +33,30 @@ pub trait Layout: ObjcAccess {
         });
     }
 
+    /// Marks this view as needing a layout pass, which the system will perform before the next
+    /// display. Call this after changing state that affects constraints or subview positions.
+    fn set_needs_layout(&self) {
+        self.with_backing_obj_mut(|obj| unsafe {
+            #[cfg(feature = "appkit")]
+            let _: () = msg_send![obj, setNeedsLayout: YES];
+
+            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            let _: () = msg_send![obj, setNeedsLayout];
+        });
+    }
+
+    /// Immediately performs any pending layout for this view and its subviews, rather than
+    /// waiting on the next display pass - e.g, so that `frame()` reflects recent changes.
+    fn layout_if_needed(&self) {
+        self.with_backing_obj_mut(|obj| unsafe {
+            #[cfg(feature = "appkit")]
+            let _: () = msg_send![obj, layoutSubtreeIfNeeded];
+
+            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            let _: () = msg_send![obj, layoutIfNeeded];
+        });
+    }
+
     /// Adds another Layout-backed control or view as a subview of this view.
     fn add_subview<V: Layout>(&self, view: &V) {
         self.with_backing_obj_mut(|backing_node| {
@@ -41,6 +66,24 @@ pub trait Layout: ObjcAccess {
         });
     }
 
+    /// Returns the (retained) backing nodes of this view's subviews, ordered back to front. These
+    /// aren't wrapped, as we can't know what type of view each one is; they're mostly useful for
+    /// introspection, or passing back into `msg_send!`.
+    fn subviews(&self) -> Vec<ShareId<Object>> {
+        self.get_from_backing_obj(|obj| unsafe {
+            let subviews = NSArray::retain(msg_send![obj, subviews]);
+            subviews.map(|subview| ShareId::from_ptr(subview))
+        })
+    }
+
+    /// Removes every subview from this view.
+    fn remove_all_subviews(&self) {
+        // `subviews` returns a copy, so it's safe to mutate the hierarchy as we go.
+        for subview in self.subviews() {
+            let _: () = unsafe { msg_send![&*subview, removeFromSuperview] };
+        }
+    }
+
     /// Removes a control or view from the superview.
     fn remove_from_superview(&self) {
         self.with_backing_obj_mut(|backing_node| unsafe {
@@ -48,6 +91,22 @@ pub trait Layout: ObjcAccess {
         });
     }
 
+    /// Removes `view` from this view, if it's a direct subview; otherwise, this does nothing.
+    ///
+    /// The Rust-side wrapper still holds a strong reference to the backing node, so the view (and
+    /// its delegate) stay alive and can be added back later.
+    fn remove_subview<V: Layout>(&self, view: &V) {
+        self.with_backing_obj_mut(|backing_node| {
+            view.with_backing_obj_mut(|subview_node| unsafe {
+                let superview: id = msg_send![subview_node, superview];
+
+                if superview == backing_node {
+                    let _: () = msg_send![subview_node, removeFromSuperview];
+                }
+            });
+        });
+    }
+
     /// Sets the `frame` for the view this trait is applied to.
     ///
     /// Note that Cacao, by default, opts into autolayout - you need to call
@@ -61,6 +120,56 @@ pub trait Layout: ObjcAccess {
         });
     }
 
+    /// Returns the current `frame` of the view this trait is applied to, in its superview's
+    /// coordinate space. When using autolayout, this reflects the last layout pass.
+    fn frame(&self) -> Rect {
+        let frame: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, frame] });
+        frame.into()
+    }
+
+    /// Converts `point` from the coordinate space of `view` into the coordinate space of this
+    /// view. Both views must be in the same window.
+    fn convert_point<V: Layout>(&self, point: Point, view: &V) -> Point {
+        let point: CGPoint = point.into();
+
+        let converted: CGPoint = self.get_from_backing_obj(|obj| {
+            view.get_from_backing_obj(|other| unsafe { msg_send![obj, convertPoint:point fromView:other] })
+        });
+
+        converted.into()
+    }
+
+    /// Converts `point` from the coordinate space of this view into the coordinate space of
+    /// `view`. Both views must be in the same window.
+    fn convert_point_to<V: Layout>(&self, point: Point, view: &V) -> Point {
+        let point: CGPoint = point.into();
+
+        let converted: CGPoint = self.get_from_backing_obj(|obj| {
+            view.get_from_backing_obj(|other| unsafe { msg_send![obj, convertPoint:point toView:other] })
+        });
+
+        converted.into()
+    }
+
+    /// Converts `point` from window coordinates (e.g, the location of a mouse event) into the
+    /// coordinate space of this view.
+    fn convert_point_from_window(&self, point: Point) -> Point {
+        let point: CGPoint = point.into();
+
+        let converted: CGPoint = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertPoint:point fromView:nil] });
+
+        converted.into()
+    }
+
+    /// Converts `point` from the coordinate space of this view into window coordinates.
+    fn convert_point_to_window(&self, point: Point) -> Point {
+        let point: CGPoint = point.into();
+
+        let converted: CGPoint = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertPoint:point toView:nil] });
+
+        converted.into()
+    }
+
     /// Sets whether the view for this trait should translate autoresizing masks into layout
     /// constraints.
     ///
@@ -104,18 +213,17 @@ pub trait Layout: ObjcAccess {
 
     /// Register this view for drag and drop operations.
     ///
+    /// The backing view exists from construction, so this can be called at any point - e.g,
+    /// before the view has been added to a superview or window.
+    ///
     /// This should be supported under UIKit as well, but is featured gated under AppKit
     /// currently to avoid compile issues.
     #[cfg(feature = "appkit")]
     fn register_for_dragged_types(&self, types: &[PasteboardType]) {
-        let types: NSArray = types
-            .into_iter()
-            .map(|t| {
-                let x: NSString = (*t).into();
-                x.into()
-            })
-            .collect::<Vec<id>>()
-            .into();
+        // The strings need to outlive the array construction; converting each straight into an
+        // `id` would release it before the array had a chance to retain it.
+        let types: Vec<NSString> = types.iter().map(|t| (*t).into()).collect();
+        let types: NSArray = types.iter().map(|t| &**t).collect::<Vec<&Object>>().into();
 
         self.with_backing_obj_mut(|obj| unsafe {
             let _: () = msg_send![obj, registerForDraggedTypes:&*types];
@@ -161,6 +269,28 @@ pub trait Layout: ObjcAccess {
         });
     }
 
+    /// Sets the background color of the backing layer directly, opting the view into being
+    /// layer-backed if it isn't already.
+    ///
+    /// This differs from `set_background_color()` on widgets like `View`: there, the color is
+    /// stored and applied when the system asks the view to update its layer, which means dynamic
+    /// colors follow appearance changes (e.g, light/dark mode). Here, the color is resolved to a
+    /// `CGColor` immediately and set on the layer - it takes effect right away and composites
+    /// with layer properties like corner radius, but it will _not_ update if the appearance
+    /// changes. If you need that, call this again from an appearance change handler.
+    fn set_layer_background_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.with_backing_obj_mut(|obj| unsafe {
+            #[cfg(feature = "appkit")]
+            let _: () = msg_send![obj, setWantsLayer: YES];
+
+            let layer: id = msg_send![obj, layer];
+            let cg: id = msg_send![color, CGColor];
+            let _: () = msg_send![layer, setBackgroundColor: cg];
+        });
+    }
+
     /// Theoretically this belongs elsewhere, but we want to enable this on all view layers, since
     /// it's common enough anyway.
     #[cfg(feature = "appkit")]
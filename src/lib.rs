@@ -99,11 +99,14 @@ pub use url;
 //#[cfg(all(feature = "appkit", feature = "uikit", not(feature = "doc_cfg")))]
 //compile_error!("The \"appkit\" and \"uikit\" features cannot be enabled together. Pick one. :)");
 
+#[cfg(feature = "appkit")]
+pub mod accessibility;
+
 #[cfg(feature = "appkit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "appkit")))]
 pub mod appkit;
 
-//pub mod bundle;
+pub mod bundle;
 
 #[cfg(feature = "uikit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "uikit")))]
@@ -130,6 +133,7 @@ pub mod error;
 pub mod events;
 
 pub mod defaults;
+pub mod dispatch;
 
 #[cfg(any(feature = "appkit", feature = "uikit"))]
 pub mod filesystem;
@@ -173,6 +177,8 @@ pub mod select;
 #[cfg(feature = "appkit")]
 pub mod text;
 
+pub mod timer;
+
 #[cfg(feature = "quicklook")]
 #[cfg_attr(docsrs, doc(cfg(feature = "quicklook")))]
 pub mod quicklook;
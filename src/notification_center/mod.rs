@@ -11,63 +11,110 @@
 //! integrating with certain aspects of the underlying Cocoa/Foundation/Kit frameworks.
 //!
 //! ## Example
+//!
+//! ```rust,no_run
+//! use cacao::notification_center::NotificationCenter;
+//!
+//! let center = NotificationCenter::default();
+//!
+//! // Keep the token around for as long as you want to receive notifications; dropping it
+//! // removes the observer.
+//! let token = center.observe("NSApplicationDidBecomeActiveNotification", |notification| {
+//!     println!("Received {}", notification.name());
+//! });
+//! ```
 
-//use std::sync::Mutex;
-//use std::collections::HashMap;
+use block::ConcreteBlock;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
 
-//use lazy_static::lazy_static;
-//use objc::{class, msg_send, sel, sel_impl};
-//use objc::runtime::Object;
-//use objc_id::ShareId;
+use crate::foundation::{id, nil, NSString};
 
 mod name;
 pub use name::NotificationName;
 
+mod notification;
+pub use notification::Notification;
+
 mod traits;
 pub use traits::Dispatcher;
 
-/*lazy_static! {
-    pub static ref DefaultNotificationCenter: NotificationCenter = {
-        NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
-            },
+/// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
+/// default center; in the future it should aim to support custom variants.
+#[derive(Clone, Debug)]
+pub struct NotificationCenter {
+    /// A pointer to the underlying `NSNotificationCenter`.
+    pub objc: ShareId<Object>
+}
 
-            subscribers: Mutex::new(HashMap::new())
-        }
-    };
-}*/
-
-// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
-// default center; in the future it should aim to support custom variants.
-//#[derive(Debug)]
-//pub struct NotificationCenter {
-//    pub objc: ShareId<Object>,
-//pub subscribers: Mutex<HashMap<String, Vec<Dispatcher>>>
-//}
-
-/*impl Default for NotificationCenter {
+impl Default for NotificationCenter {
     /// Returns a wrapper over `[NSNotificationCenter defaultCenter]`. From here you can handle
-    /// observing, removing, and posting notifications.
+    /// observing and posting notifications.
     fn default() -> Self {
         NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
-            }
+            objc: unsafe { ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter]) }
         }
     }
-}*/
+}
 
-/*impl NotificationCenter {
-    pub fn observe<T: Dispatcher>(&self, name: &str, handler: &T) {
+impl NotificationCenter {
+    /// Registers `handler` to be called whenever a notification with the given name is posted.
+    ///
+    /// The handler is always invoked on the main thread (via the main operation queue), even if
+    /// the notification is posted from a background thread. The observer stays registered for as long as the
+    /// returned `NotificationToken` is alive - dropping it removes the observer.
+    pub fn observe<F>(&self, name: &str, handler: F) -> NotificationToken
+    where
+        F: Fn(Notification) + 'static
+    {
+        let name = NSString::new(name);
 
-    }
+        let block = ConcreteBlock::new(move |notification: id| {
+            handler(Notification::retain(notification));
+        });
+        let block = block.copy();
 
-    pub fn remove<T: Dispatcher>(&self, name: &str, handler: &T) {
+        let observer = unsafe {
+            let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+            let observer: id = msg_send![&*self.objc, addObserverForName:&*name
+                object:nil
+                queue:queue
+                usingBlock:&*block
+            ];
 
+            ShareId::from_ptr(observer)
+        };
+
+        NotificationToken {
+            center: self.objc.clone(),
+            observer
+        }
     }
 
+    /// Posts a notification with the given name, with no object or `userInfo` attached.
     pub fn post(&self, name: &str) {
+        let name = NSString::new(name);
 
+        unsafe {
+            let _: () = msg_send![&*self.objc, postNotificationName:&*name object:nil];
+        }
+    }
+}
+
+/// Returned from `NotificationCenter::observe()`. Holds the underlying observer, and removes it
+/// from the notification center when dropped.
+#[derive(Debug)]
+pub struct NotificationToken {
+    center: ShareId<Object>,
+    observer: ShareId<Object>
+}
+
+impl Drop for NotificationToken {
+    /// Removes the observer from the notification center it was registered with.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.center, removeObserver:&*self.observer];
+        }
     }
-}*/
+}
@@ -0,0 +1,55 @@
+use objc::runtime::Object;
+use objc::{msg_send, sel, sel_impl};
+use objc_id::{Id, ShareId};
+
+use crate::foundation::{id, nil, NSMutableDictionary, NSString};
+
+/// Wraps an `NSNotification` that's been delivered to an observer.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// A pointer to the underlying `NSNotification`.
+    pub objc: ShareId<Object>
+}
+
+impl Notification {
+    /// In cases where we're vended an `NSNotification` by the system, this can be used to wrap
+    /// and retain it.
+    pub fn retain(notification: id) -> Self {
+        Notification {
+            objc: unsafe { ShareId::from_ptr(notification) }
+        }
+    }
+
+    /// Returns the name of this notification.
+    pub fn name(&self) -> String {
+        NSString::retain(unsafe { msg_send![&*self.objc, name] }).to_string()
+    }
+
+    /// Returns the object associated with this notification, if there is one. This is typically
+    /// the object that posted it.
+    pub fn object(&self) -> Option<id> {
+        let object: id = unsafe { msg_send![&*self.objc, object] };
+
+        match object.is_null() {
+            true => None,
+            false => Some(object)
+        }
+    }
+
+    /// Returns the `userInfo` dictionary attached to this notification, if there is one.
+    ///
+    /// The dictionary vended by the system is immutable; this hands back a mutable copy, so any
+    /// changes you make won't be visible to other observers.
+    pub fn user_info(&self) -> Option<NSMutableDictionary> {
+        unsafe {
+            let user_info: id = msg_send![&*self.objc, userInfo];
+
+            if user_info == nil {
+                return None;
+            }
+
+            let copy: id = msg_send![user_info, mutableCopy];
+            Some(NSMutableDictionary(Id::from_retained_ptr(copy)))
+        }
+    }
+}
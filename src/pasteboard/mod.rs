@@ -14,6 +14,7 @@
 //! ```
 
 use std::path::PathBuf;
+use std::time::Duration;
 
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
@@ -21,7 +22,8 @@ use objc_id::ShareId;
 use url::Url;
 
 use crate::error::Error;
-use crate::foundation::{id, nil, NSArray, NSString, NSURL};
+use crate::foundation::{id, nil, NSArray, NSDictionary, NSInteger, NSNumber, NSString, NSURL};
+use crate::timer::Timer;
 
 mod types;
 pub use types::{PasteboardName, PasteboardType};
@@ -38,6 +40,11 @@ impl Default for Pasteboard {
 }
 
 impl Pasteboard {
+    /// Returns the general (system-wide copy/paste) pasteboard. Equivalent to `default()`.
+    pub fn general() -> Self {
+        Pasteboard::default()
+    }
+
     /// Used internally for wrapping a Pasteboard returned from operations (say, drag and drop).
     pub(crate) fn with(existing: id) -> Self {
         Pasteboard(unsafe { ShareId::from_ptr(existing) })
@@ -67,6 +74,53 @@ impl Pasteboard {
         }
     }
 
+    /// Clears the pasteboard and writes `text` to it, taking ownership of its contents. Use this
+    /// when the text is all you're providing; `copy_text()` leaves existing contents alone.
+    pub fn write_string(&self, text: &str) {
+        self.clear_contents();
+        self.copy_text(text);
+    }
+
+    /// Returns the string on the pasteboard, if there is one.
+    pub fn read_string(&self) -> Option<String> {
+        let ptype: NSString = PasteboardType::String.into();
+        NSString::to_string_opt(unsafe { msg_send![&*self.0, stringForType:&*ptype] })
+    }
+
+    /// Returns every string on the pasteboard (e.g, when multiple items have been dragged in).
+    pub fn read_strings(&self) -> Vec<String> {
+        unsafe {
+            let class: id = msg_send![class!(NSString), class];
+            let classes = NSArray::new(&[class]);
+            let contents: id = msg_send![&*self.0, readObjectsForClasses:classes options:nil];
+
+            match contents == nil {
+                true => vec![],
+                false => NSArray::retain(contents).map(|string| NSString::retain(string).to_string())
+            }
+        }
+    }
+
+    /// Returns the (absolute string form of) every file URL on the pasteboard - e.g, files dropped
+    /// from Finder. Non-file URLs are skipped. If you need `NSURL`s or paths, see
+    /// `get_file_urls()`.
+    pub fn read_file_urls(&self) -> Vec<String> {
+        let mut file_urls_only = NSNumber::bool(true);
+        let options = NSDictionary::new();
+        options.insert("NSPasteboardURLReadingFileURLsOnlyKey", &mut *file_urls_only.0);
+
+        unsafe {
+            let class: id = msg_send![class!(NSURL), class];
+            let classes = NSArray::new(&[class]);
+            let contents: id = msg_send![&*self.0, readObjectsForClasses:classes options:options.as_id()];
+
+            match contents == nil {
+                true => vec![],
+                false => NSArray::retain(contents).map(|url| NSURL::retain(url).absolute_string())
+            }
+        }
+    }
+
     /// Releases the receiver’s resources in the pasteboard server. It's rare-ish to need to use
     /// this, but considering this stuff happens on the Objective-C side you may need it.
     pub fn release_globally(&self) {
@@ -82,6 +136,35 @@ impl Pasteboard {
         }
     }
 
+    /// Returns the pasteboard's change count, which increments every time its contents change
+    /// (i.e, whenever ownership of the pasteboard changes).
+    pub fn change_count(&self) -> NSInteger {
+        unsafe { msg_send![&*self.0, changeCount] }
+    }
+
+    /// Calls `callback` whenever the contents of this pasteboard change.
+    ///
+    /// AppKit has no notification for pasteboard changes, so this is poll-based: every
+    /// `poll_interval`, the `change_count()` is compared against the last one seen, and
+    /// `callback` fires if it's been incremented. Keep intervals reasonable (e.g, half a second);
+    /// the check is cheap, but it does wake your app up.
+    ///
+    /// Polling stops when the returned `Timer` is dropped (or invalidated), so hold on to it. As
+    /// with any `Timer`, call this from the main thread.
+    pub fn on_change<F: Fn() + 'static>(&self, poll_interval: Duration, callback: F) -> Timer {
+        let pasteboard = Pasteboard(self.0.clone());
+        let mut last_count = self.change_count();
+
+        Timer::scheduled(poll_interval, true, move || {
+            let count = pasteboard.change_count();
+
+            if count != last_count {
+                last_count = count;
+                callback();
+            }
+        })
+    }
+
     /// Looks inside the pasteboard contents and extracts what FileURLs are there, if any.
     ///
     /// _Note that this method returns a list of `Url` entities, in an attempt to be closer to how
@@ -99,7 +182,7 @@ impl Pasteboard {
                 // This error is not necessarily "correct", but in the event of an error in
                 // Pasteboard server retrieval I'm not sure where to check... and this stuff is
                 // kinda ancient and has conflicting docs in places. ;P
-                return Err(Box::new(Error {
+                return Err(Box::new(Error::NSError {
                     code: 666,
                     domain: "com.cacao-rs.pasteboard".to_string(),
                     description: "Pasteboard server returned no data.".to_string()
@@ -1,7 +1,7 @@
 use crate::foundation::NSString;
 
 /// Constants for the standard system pasteboard names.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum PasteboardName {
     /// The dragging/dropping pasteboard.
     Drag,
@@ -32,7 +32,7 @@ impl From<PasteboardName> for NSString<'_> {
 }
 
 /// Represents different Pasteboard types that can be referred to.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum PasteboardType {
     /// URL data for one file or resource.
     URL,
@@ -43,6 +43,8 @@
 //!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
+use core_graphics::base::CGFloat;
+
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 use objc_id::ShareId;
@@ -86,6 +88,12 @@ fn allocate_view(registration_fn: fn() -> *const Class) -> id {
             // We sub this in to get the general expected behavior for 202*.
             let _: () = msg_send![label, setSelectable: NO];
 
+            // These are already the defaults for a wrapping label, but we're explicit about it
+            // since a bezeled or editable label would report a different intrinsic size.
+            let _: () = msg_send![label, setBezeled: NO];
+            let _: () = msg_send![label, setEditable: NO];
+            let _: () = msg_send![label, setDrawsBackground: NO];
+
             label
         };
 
@@ -425,6 +433,20 @@ impl<T> Label<T> {
         });
     }
 
+    /// Sets the width at which text in this label should wrap. The label's intrinsic height is
+    /// computed from this, so for multi-line labels in an autolayout context you'll typically want
+    /// to set it to the width you expect the label to be laid out at.
+    ///
+    /// Passing `0.` (the default) lets AppKit determine it from the label's current frame.
+    #[cfg(feature = "appkit")]
+    pub fn set_preferred_max_layout_width(&self, width: f64) {
+        let width: CGFloat = width.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setPreferredMaxLayoutWidth: width];
+        });
+    }
+
     /// Set the line break mode for this label.
     pub fn set_line_break_mode(&self, mode: LineBreakMode) {
         #[cfg(feature = "appkit")]
@@ -0,0 +1,108 @@
+//! Wraps `NSTimer`, for running a callback after a delay or on an interval - e.g, polling, or
+//! driving a simple animation.
+//!
+//! Timers are always scheduled on the _main_ run loop (in the common modes, so they continue to
+//! fire during things like scrolling or window resizing), meaning your callback runs on the main
+//! thread and is free to do UI work.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::timer::Timer;
+//!
+//! let mut ticks = 0;
+//!
+//! // Keep the timer around for as long as you want it to fire; dropping it invalidates it.
+//! let timer = Timer::scheduled(Duration::from_secs(1), true, move || {
+//!     ticks += 1;
+//!     println!("Tick {}", ticks);
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use block::ConcreteBlock;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, to_bool, BOOL, NO, YES};
+
+extern "C" {
+    static NSRunLoopCommonModes: id;
+}
+
+/// A handle to a scheduled `NSTimer`. The timer is invalidated when this is dropped, so you need
+/// to hold on to it for as long as you want your callback to fire.
+#[derive(Debug)]
+pub struct Timer {
+    /// A pointer to the underlying `NSTimer`.
+    pub objc: ShareId<Object>
+}
+
+impl Timer {
+    /// Creates a timer that calls `handler` after `interval` has elapsed, and schedules it on the
+    /// main run loop. If `repeats` is `true`, the handler will continue to be called every
+    /// `interval` until the timer is invalidated (or dropped).
+    ///
+    /// Note that `NSRunLoop` is not thread safe; you should create timers from the main thread.
+    pub fn scheduled<F>(interval: Duration, repeats: bool, handler: F) -> Self
+    where
+        F: FnMut() + 'static
+    {
+        // Blocks are `Fn`, but a timer callback that can't hold state isn't terribly useful.
+        let handler = RefCell::new(handler);
+        let block = ConcreteBlock::new(move |_timer: id| {
+            let mut handler = handler.borrow_mut();
+            (*handler)();
+        });
+
+        let objc = unsafe {
+            let timer: id = msg_send![class!(NSTimer), timerWithTimeInterval:interval.as_secs_f64()
+                repeats:match repeats {
+                    true => YES,
+                    false => NO
+                }
+                block:block.copy()
+            ];
+
+            let run_loop: id = msg_send![class!(NSRunLoop), mainRunLoop];
+            let _: () = msg_send![run_loop, addTimer:timer forMode:NSRunLoopCommonModes];
+
+            ShareId::from_ptr(timer)
+        };
+
+        Timer { objc }
+    }
+
+    /// Fires the timer immediately, calling the handler. For repeating timers, this does not
+    /// affect the regular firing schedule.
+    pub fn fire(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, fire];
+        }
+    }
+
+    /// Stops the timer from ever firing again. This is called for you when the `Timer` is
+    /// dropped.
+    pub fn invalidate(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, invalidate];
+        }
+    }
+
+    /// Returns whether the timer is still valid - i.e, it hasn't been invalidated, and (for
+    /// non-repeating timers) hasn't fired yet.
+    pub fn is_valid(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, isValid] };
+        to_bool(result)
+    }
+}
+
+impl Drop for Timer {
+    /// Invalidates the underlying `NSTimer`, which removes it from the run loop and releases the
+    /// handler.
+    fn drop(&mut self) {
+        self.invalidate();
+    }
+}
@@ -11,6 +11,7 @@ use objc::{Encode, Encoding};
 use objc_id::ShareId;
 
 use crate::foundation::{id, BOOL, NO, YES};
+use crate::geometry::Rect;
 
 mod cell_factory;
 pub use cell_factory::CellFactory;
@@ -49,6 +50,29 @@ pub fn load<'a, T>(this: &'a Object, ptr_name: &str) -> &'a T {
     }
 }
 
+/// Like `load`, but returns `None` if the pointer has been cleared via `clear_ptr()` - e.g,
+/// because the owning Rust component was dropped while the Objective-C object lives on (retained
+/// by a window, superview, or the like). Use this for callbacks on objects that can outlive their
+/// Rust wrapper.
+pub fn load_opt<'a, T>(this: &'a Object, ptr_name: &str) -> Option<&'a T> {
+    unsafe {
+        let ptr: usize = *this.get_ivar(ptr_name);
+
+        match ptr {
+            0 => None,
+            ptr => Some(&*(ptr as *const T))
+        }
+    }
+}
+
+/// Zeroes a pointer ivar, so that subsequent `load_opt()` calls return `None` rather than a
+/// dangling reference. Owning components call this when they're dropped.
+pub(crate) fn clear_ptr(this: id, ptr_name: &str) {
+    unsafe {
+        (&mut *this).set_ivar(ptr_name, 0usize);
+    }
+}
+
 /// Asynchronously execute a callback on the main thread via Grand Central Dispatch.
 pub fn async_main_thread<F>(method: F)
 where
@@ -100,6 +124,59 @@ unsafe impl Encode for CGSize {
     }
 }
 
+/// See the notes on `CGSize`; this is used for points passed to us (e.g, in `hitTest:`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CGPoint {
+    /// The x coordinate of this point.
+    pub x: CGFloat,
+
+    /// The y coordinate of this point.
+    pub y: CGFloat
+}
+
+impl CGPoint {
+    /// Create and return a new `CGPoint`.
+    pub fn new(x: CGFloat, y: CGFloat) -> Self {
+        CGPoint { x, y }
+    }
+}
+
+unsafe impl Encode for CGPoint {
+    /// Adds support for CGPoint Objective-C encoding.
+    fn encode() -> Encoding {
+        let encoding = format!("{{CGPoint={}{}}}", CGFloat::encode().as_str(), CGFloat::encode().as_str());
+
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+/// See the notes on `CGSize`; this is used for rects passed to us (e.g, in `drawRect:`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CGRect {
+    /// The origin of this rect.
+    pub origin: CGPoint,
+
+    /// The size of this rect.
+    pub size: CGSize
+}
+
+unsafe impl Encode for CGRect {
+    /// Adds support for CGRect Objective-C encoding.
+    fn encode() -> Encoding {
+        let encoding = format!("{{CGRect={}{}}}", CGPoint::encode().as_str(), CGSize::encode().as_str());
+
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+impl From<CGRect> for Rect {
+    fn from(rect: CGRect) -> Rect {
+        Rect::new(rect.origin.y, rect.origin.x, rect.size.width, rect.size.height)
+    }
+}
+
 /// A helper method for ensuring that Cocoa is running in multi-threaded mode.
 ///
 /// Why do we need this? According to Apple, if you're going to make use of standard POSIX threads,
@@ -18,12 +18,19 @@ use crate::foundation::id;
 pub struct ObjcProperty(Rc<RefCell<Id<Object>>>);
 
 impl ObjcProperty {
-    /// Given an Objective-C object, retains it and wraps it as a `Property`.
+    /// Given an Objective-C object, retains it and wraps it as a `Property`. Equivalent to
+    /// `from_unretained()`.
     pub fn retain(obj: id) -> Self {
+        ObjcProperty::from_unretained(obj)
+    }
+
+    /// Given an Objective-C object that we don't own, retains it and wraps it as a `Property`.
+    pub fn from_unretained(obj: id) -> Self {
         ObjcProperty(Rc::new(RefCell::new(unsafe { Id::from_ptr(obj) })))
     }
 
-    /// Given an Objective-C object, retains it and wraps it as a `Property`.
+    /// Given an Objective-C object that we already own (e.g, from `alloc`/`init` or `new`),
+    /// wraps it as a `Property` without an additional retain.
     pub fn from_retained(obj: id) -> Self {
         ObjcProperty(Rc::new(RefCell::new(unsafe { Id::from_retained_ptr(obj) })))
     }
@@ -3,39 +3,75 @@
 //!
 //! I kid, I kid.
 //!
-//! It just enforces that coordinates are judged from the top-left, which is what most people look
-//! for in the modern era. It also implements a few helpers for things like setting a background
-//! color, and enforcing layer backing by default.
+//! It just enforces that coordinates are judged from the top-left (by default), which is what most
+//! people look for in the modern era. It also implements a few helpers for things like setting a
+//! background color, and enforcing layer backing by default.
 
 use std::sync::Once;
 
+use core_graphics::geometry::CGRect;
+
 use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
-use crate::dragdrop::DragInfo;
-use crate::foundation::{id, load_or_register_class, nil, NSUInteger, NO, YES};
-use crate::utils::load;
-use crate::view::{ViewDelegate, BACKGROUND_COLOR, VIEW_DELEGATE_PTR};
+use crate::appkit::ScrollEvent;
+use crate::dragdrop::{DragInfo, DragOperation};
+use crate::foundation::{id, load_or_register_class, nil, Callback, DelegateContainer, NSInteger, NSUInteger, NO, YES};
+use crate::geometry::Point;
+use crate::utils::{load_opt, CGPoint, CGRect as DrawRect};
+use crate::view::{
+    GraphicsContext, HitTestResult, ViewDelegate, BACKGROUND_COLOR, IS_FLIPPED, MOUSE_ENTERED_HANDLER, MOUSE_EXITED_HANDLER,
+    TRACKING_AREA, VIEW_DELEGATE_PTR
+};
+
+/// `NSTrackingMouseEnteredAndExited`.
+const NSTRACKING_MOUSE_ENTERED_AND_EXITED: NSUInteger = 0x01;
+
+/// `NSTrackingActiveInActiveApp`.
+const NSTRACKING_ACTIVE_IN_ACTIVE_APP: NSUInteger = 0x40;
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
 extern "C" fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
     return YES;
 }
 
+/// Returns whether this view is flipped, as configured via `View::set_flipped()`.
+extern "C" fn is_flipped(this: &Object, _: Sel) -> BOOL {
+    unsafe { *this.get_ivar(IS_FLIPPED) }
+}
+
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return DragOperation::None.into()
+    };
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     })
     .into()
 }
 
+/// Called periodically as a drag/drop operation moves within this view.
+extern "C" fn dragging_updated<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return DragOperation::None.into()
+    };
+    view.dragging_updated(DragInfo {
+        info: unsafe { Id::from_ptr(info) }
+    })
+    .into()
+}
+
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return NO
+    };
 
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -47,11 +83,18 @@ extern "C" fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _:
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return NO
+    };
 
-    match view.perform_drag_operation(DragInfo {
+    let info = DragInfo {
         info: unsafe { Id::from_ptr(info) }
-    }) {
+    };
+
+    // `dropped()` is the simpler hook; it only gets a say if `perform_drag_operation()` didn't
+    // handle the drop.
+    match view.perform_drag_operation(info.clone()) || view.dropped(&info.get_pasteboard()) {
         true => YES,
         false => NO
     }
@@ -59,7 +102,10 @@ extern "C" fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel,
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
 
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -68,13 +114,147 @@ extern "C" fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn dragging_exited<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
 
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });
 }
 
+/// Called when a drag that this view began needs to know which operations are supported.
+extern "C" fn dragging_source_operation<T: ViewDelegate>(this: &mut Object, _: Sel, _: id, context: NSInteger) -> NSUInteger {
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return DragOperation::None.into()
+    };
+    view.dragging_source_operation(context == 1).into()
+}
+
+/// Called when a drag that a delegate-less view began needs to know which operations are
+/// supported. Without a delegate to ask, we just allow copying.
+extern "C" fn default_dragging_source_operation(_: &Object, _: Sel, _: id, _: NSInteger) -> NSUInteger {
+    DragOperation::Copy.into()
+}
+
+/// Called when this view receives a scroll wheel event. If the delegate doesn't handle it, we pass
+/// it on to the default implementation.
+extern "C" fn scroll_wheel<T: ViewDelegate>(this: &mut Object, _: Sel, event: id) {
+    let handled = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view.scroll_wheel(ScrollEvent::new(event)),
+        None => false
+    };
+
+    if !handled {
+        unsafe {
+            let _: () = msg_send![super(this, class!(NSView)), scrollWheel: event];
+        }
+    }
+}
+
+/// Called when hit testing this view. `point` is in the superview's coordinate space, so we convert
+/// it before handing it to the delegate.
+extern "C" fn hit_test<T: ViewDelegate>(this: &mut Object, _: Sel, point: CGPoint) -> id {
+    let result = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => {
+            let local: CGPoint = unsafe {
+                let superview: id = msg_send![this, superview];
+                msg_send![this, convertPoint:point fromView:superview]
+            };
+
+            view.hit_test(Point::new(local.x, local.y))
+        },
+
+        None => HitTestResult::Pass
+    };
+
+    match result {
+        HitTestResult::Pass => unsafe { msg_send![super(this, class!(NSView)), hitTest: point] },
+        HitTestResult::Ignore => nil,
+        HitTestResult::Claim => this
+    }
+}
+
+/// Fires the mouse handler stored in the given ivar, if there is one.
+fn fire_mouse_handler(this: &Object, ivar: &str) {
+    if let Some(handler) = unsafe { DelegateContainer::<Callback>::get(this, ivar) } {
+        handler();
+    }
+}
+
+/// Called when the mouse enters this view's tracking area.
+extern "C" fn mouse_entered(this: &Object, _: Sel, _: id) {
+    fire_mouse_handler(this, MOUSE_ENTERED_HANDLER);
+}
+
+/// Called when the mouse exits this view's tracking area.
+extern "C" fn mouse_exited(this: &Object, _: Sel, _: id) {
+    fire_mouse_handler(this, MOUSE_EXITED_HANDLER);
+}
+
+/// Called by AppKit whenever tracking areas need to be recalculated (e.g, when the bounds change).
+/// If any hover handlers are set, we throw out our existing tracking area and install a new one
+/// matching the current bounds.
+extern "C" fn update_tracking_areas(this: &mut Object, _: Sel) {
+    unsafe {
+        let existing: id = *this.get_ivar(TRACKING_AREA);
+
+        if existing != nil {
+            let _: () = msg_send![this, removeTrackingArea: existing];
+            let _: () = msg_send![existing, release];
+            this.set_ivar(TRACKING_AREA, nil);
+        }
+
+        let entered: usize = *this.get_ivar(MOUSE_ENTERED_HANDLER);
+        let exited: usize = *this.get_ivar(MOUSE_EXITED_HANDLER);
+
+        if entered != 0 || exited != 0 {
+            let bounds: CGRect = msg_send![this, bounds];
+            let options = NSTRACKING_MOUSE_ENTERED_AND_EXITED | NSTRACKING_ACTIVE_IN_ACTIVE_APP;
+
+            let alloc: id = msg_send![class!(NSTrackingArea), alloc];
+            let area: id = msg_send![alloc, initWithRect:bounds options:options owner:&*this userInfo:nil];
+            let _: () = msg_send![this, addTrackingArea: area];
+            this.set_ivar(TRACKING_AREA, area);
+        }
+
+        let _: () = msg_send![super(this, class!(NSView)), updateTrackingAreas];
+    }
+}
+
+/// Drops any hover handlers and releases the tracking area we hold, if any.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        DelegateContainer::<Callback>::clear(this, MOUSE_ENTERED_HANDLER);
+        DelegateContainer::<Callback>::clear(this, MOUSE_EXITED_HANDLER);
+
+        let area: id = *this.get_ivar(TRACKING_AREA);
+
+        if area != nil {
+            let _: () = msg_send![area, release];
+        }
+
+        let _: () = msg_send![super(this, class!(NSView)), dealloc];
+    }
+}
+
+/// Registers the ivars and methods needed for hover (tracking area) support.
+unsafe fn add_mouse_tracking(decl: &mut ClassDecl) {
+    decl.add_ivar::<usize>(MOUSE_ENTERED_HANDLER);
+    decl.add_ivar::<usize>(MOUSE_EXITED_HANDLER);
+    decl.add_ivar::<id>(TRACKING_AREA);
+
+    decl.add_method(sel!(mouseEntered:), mouse_entered as extern "C" fn(&Object, _, _));
+    decl.add_method(sel!(mouseExited:), mouse_exited as extern "C" fn(&Object, _, _));
+    decl.add_method(
+        sel!(updateTrackingAreas),
+        update_tracking_areas as extern "C" fn(&mut Object, _)
+    );
+    decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, _));
+}
+
 /// Called for layer updates.
 extern "C" fn update_layer(this: &Object, _: Sel) {
     unsafe {
@@ -88,6 +268,40 @@ extern "C" fn update_layer(this: &Object, _: Sel) {
     }
 }
 
+/// Opts out of layer-only rendering if the delegate wants to draw its own content, as AppKit
+/// won't call `drawRect:` otherwise.
+extern "C" fn wants_update_layer<T: ViewDelegate>(this: &Object, _: Sel) -> BOOL {
+    match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) if view.draws_content() => NO,
+        _ => YES
+    }
+}
+
+/// Called when the view needs to draw its content. As `updateLayer` isn't called in this case,
+/// we fill in the background color ourselves before handing off to the delegate.
+extern "C" fn draw_rect<T: ViewDelegate>(this: &Object, _: Sel, dirty_rect: DrawRect) {
+    let view = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
+
+    let context = match GraphicsContext::current() {
+        Some(context) => context,
+        None => return
+    };
+
+    unsafe {
+        let background_color: id = *this.get_ivar(BACKGROUND_COLOR);
+
+        if background_color != nil {
+            let _: () = msg_send![background_color, setFill];
+            let _: () = msg_send![class!(NSBezierPath), fillRect: dirty_rect];
+        }
+    }
+
+    view.draw(&context, dirty_rect.into());
+}
+
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
@@ -99,11 +313,20 @@ pub(crate) fn register_view_class() -> *const Class {
         let superclass = class!(NSView);
         let mut decl = ClassDecl::new("RSTView", superclass).unwrap();
 
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(&Object, _) -> BOOL);
+        decl.add_method(sel!(isFlipped), is_flipped as extern "C" fn(&Object, _) -> BOOL);
         decl.add_method(sel!(updateLayer), update_layer as extern "C" fn(&Object, _));
         decl.add_method(sel!(wantsUpdateLayer), enforce_normalcy as extern "C" fn(&Object, _) -> BOOL);
 
+        // Acting as a drag source (see `View::begin_dragging_session()`)
+        decl.add_method(
+            sel!(draggingSession:sourceOperationMaskForDraggingContext:),
+            default_dragging_source_operation as extern "C" fn(&Object, _, _, _) -> NSUInteger
+        );
+
         decl.add_ivar::<id>(BACKGROUND_COLOR);
+        decl.add_ivar::<BOOL>(IS_FLIPPED);
+
+        add_mouse_tracking(&mut decl);
 
         VIEW_CLASS = decl.register();
     });
@@ -119,12 +342,18 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
         // It's expected that this doesn't move.
         decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
         decl.add_ivar::<id>(BACKGROUND_COLOR);
+        decl.add_ivar::<BOOL>(IS_FLIPPED);
 
         decl.add_method(sel!(updateLayer), update_layer as extern "C" fn(&Object, _));
 
-        decl.add_method(sel!(wantsUpdateLayer), enforce_normalcy as extern "C" fn(&Object, _) -> BOOL);
+        decl.add_method(
+            sel!(wantsUpdateLayer),
+            wants_update_layer::<T> as extern "C" fn(&Object, _) -> BOOL
+        );
+
+        decl.add_method(sel!(drawRect:), draw_rect::<T> as extern "C" fn(&Object, _, _));
 
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(&Object, _) -> BOOL);
+        decl.add_method(sel!(isFlipped), is_flipped as extern "C" fn(&Object, _) -> BOOL);
 
         // Drag and drop operations (e.g, accepting files)
         decl.add_method(
@@ -132,6 +361,11 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
             dragging_entered::<T> as extern "C" fn(&mut Object, _, _) -> NSUInteger
         );
 
+        decl.add_method(
+            sel!(draggingUpdated:),
+            dragging_updated::<T> as extern "C" fn(&mut Object, _, _) -> NSUInteger
+        );
+
         decl.add_method(
             sel!(prepareForDragOperation:),
             prepare_for_drag_operation::<T> as extern "C" fn(&mut Object, _, _) -> BOOL
@@ -151,5 +385,17 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
             sel!(draggingExited:),
             dragging_exited::<T> as extern "C" fn(&mut Object, _, _)
         );
+
+        // Acting as a drag source (see `View::begin_dragging_session()`)
+        decl.add_method(
+            sel!(draggingSession:sourceOperationMaskForDraggingContext:),
+            dragging_source_operation::<T> as extern "C" fn(&mut Object, _, _, _) -> NSUInteger
+        );
+
+        decl.add_method(sel!(scrollWheel:), scroll_wheel::<T> as extern "C" fn(&mut Object, _, _));
+        decl.add_method(sel!(hitTest:), hit_test::<T> as extern "C" fn(&mut Object, _, CGPoint) -> id);
+
+        // Hover support (see `View::on_mouse_entered()`)
+        add_mouse_tracking(decl);
     })
 }
@@ -6,8 +6,8 @@ use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::load_or_register_class;
-use crate::utils::load;
+use crate::foundation::{id, load_or_register_class, nil, to_bool, BOOL, NO, YES};
+use crate::utils::load_opt;
 use crate::view::{ViewDelegate, VIEW_DELEGATE_PTR};
 
 /// Called when the view controller receives a `viewWillAppear` message.
@@ -16,7 +16,10 @@ extern "C" fn will_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
         let _: () = msg_send![super(this, class!(NSViewController)), viewWillAppear];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller,
+        None => return
+    };
     controller.will_appear(false);
 }
 
@@ -26,7 +29,10 @@ extern "C" fn did_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
         let _: () = msg_send![super(this, class!(NSViewController)), viewDidAppear];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller,
+        None => return
+    };
     controller.did_appear(false);
 }
 
@@ -36,7 +42,10 @@ extern "C" fn will_disappear<T: ViewDelegate>(this: &mut Object, _: Sel) {
         let _: () = msg_send![super(this, class!(NSViewController)), viewWillDisappear];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller,
+        None => return
+    };
     controller.will_disappear(false);
 }
 
@@ -46,10 +55,88 @@ extern "C" fn did_disappear<T: ViewDelegate>(this: &mut Object, _: Sel) {
         let _: () = msg_send![super(this, class!(NSViewController)), viewDidDisappear];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller,
+        None => return
+    };
     controller.did_disappear(false);
 }
 
+/// Called when the view controller receives a `viewDidLayout` message.
+extern "C" fn did_layout<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSViewController)), viewDidLayout];
+    }
+
+    let controller = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller,
+        None => return
+    };
+    controller.did_layout();
+}
+
+/// The standard actions that we route to `ViewDelegate::perform_action()`.
+const ROUTED_ACTIONS: &[&str] = &["cut:", "copy:", "paste:", "delete:", "selectAll:"];
+
+/// Called when one of the `ROUTED_ACTIONS` is sent to the view controller. If the delegate doesn't
+/// handle it, we pass it on to the next responder.
+extern "C" fn perform_action<T: ViewDelegate>(this: &mut Object, cmd: Sel, sender: id) {
+    let handled = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller.perform_action(cmd.name()),
+        None => false
+    };
+
+    if handled {
+        return;
+    }
+
+    unsafe {
+        let next: id = msg_send![this, nextResponder];
+
+        if next != nil {
+            let _: BOOL = msg_send![next, tryToPerform:cmd with:sender];
+        }
+    }
+}
+
+/// Called when validating a menu item that targets this view controller. For our routed actions,
+/// if the delegate can't perform it, we defer to whatever would handle it further along the
+/// responder chain.
+extern "C" fn validate_menu_item<T: ViewDelegate>(this: &mut Object, _: Sel, item: id) -> BOOL {
+    let action: Sel = unsafe { msg_send![item, action] };
+    let name = action.name();
+
+    if !ROUTED_ACTIONS.contains(&name) {
+        return YES;
+    }
+
+    let can_perform = match load_opt::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(controller) => controller.can_perform_action(name),
+        None => false
+    };
+
+    if can_perform {
+        return YES;
+    }
+
+    unsafe {
+        let next: id = msg_send![this, nextResponder];
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        let target: id = msg_send![app, targetForAction:action to:next from:item];
+
+        if target == nil {
+            return NO;
+        }
+
+        let validates: BOOL = msg_send![target, respondsToSelector: sel!(validateMenuItem:)];
+
+        match to_bool(validates) {
+            true => msg_send![target, validateMenuItem: item],
+            false => YES
+        }
+    }
+}
+
 /// Registers an `NSViewDelegate`.
 pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>(instance: &T) -> *const Class {
     load_or_register_class("NSViewController", instance.subclass_name(), |decl| unsafe {
@@ -59,5 +146,17 @@ pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>(instance
         decl.add_method(sel!(viewDidAppear), did_appear::<T> as extern "C" fn(&mut Object, _));
         decl.add_method(sel!(viewWillDisappear), will_disappear::<T> as extern "C" fn(&mut Object, _));
         decl.add_method(sel!(viewDidDisappear), did_disappear::<T> as extern "C" fn(&mut Object, _));
+        decl.add_method(sel!(viewDidLayout), did_layout::<T> as extern "C" fn(&mut Object, _));
+
+        // Standard actions, routed to ViewDelegate::perform_action()
+        decl.add_method(sel!(cut:), perform_action::<T> as extern "C" fn(&mut Object, _, _));
+        decl.add_method(sel!(copy:), perform_action::<T> as extern "C" fn(&mut Object, _, _));
+        decl.add_method(sel!(paste:), perform_action::<T> as extern "C" fn(&mut Object, _, _));
+        decl.add_method(sel!(delete:), perform_action::<T> as extern "C" fn(&mut Object, _, _));
+        decl.add_method(sel!(selectAll:), perform_action::<T> as extern "C" fn(&mut Object, _, _));
+        decl.add_method(
+            sel!(validateMenuItem:),
+            validate_menu_item::<T> as extern "C" fn(&mut Object, _, _) -> BOOL
+        );
     })
 }
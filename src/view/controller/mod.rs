@@ -5,7 +5,7 @@ use objc_id::ShareId;
 use crate::foundation::id;
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
-use crate::utils::Controller;
+use crate::utils::{clear_ptr, Controller};
 use crate::view::{View, ViewDelegate, VIEW_DELEGATE_PTR};
 
 #[cfg_attr(feature = "appkit", path = "appkit.rs")]
@@ -71,8 +71,54 @@ where
     }
 }
 
+impl<T> Drop for ViewController<T> {
+    /// The underlying view controller can outlive this (e.g, if it's been set as the content view
+    /// controller of a window), so we clear its pointer to the delegate - which is about to be
+    /// dropped along with our `View` - to ensure no further callbacks reach it.
+    fn drop(&mut self) {
+        clear_ptr(&*self.objc as *const Object as id, VIEW_DELEGATE_PTR);
+    }
+}
+
 impl<T> Controller for ViewController<T> {
     fn get_backing_node(&self) -> ShareId<Object> {
         self.objc.clone()
     }
 }
+
+#[test]
+#[cfg(all(feature = "appkit", target_os = "macos"))]
+fn test_controller_outliving_rust_drop() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static CALLED: AtomicBool = AtomicBool::new(false);
+
+    struct TestDelegate;
+
+    impl ViewDelegate for TestDelegate {
+        const NAME: &'static str = "TestControllerOutlivingDropDelegate";
+
+        fn will_appear(&self, _animated: bool) {
+            CALLED.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let controller = ViewController::new(TestDelegate);
+
+    // Retain the Objective-C side past the Rust `Drop`, as e.g. a window would.
+    let objc = controller.objc.clone();
+    drop(controller);
+
+    let ptr: usize = unsafe { *objc.get_ivar(VIEW_DELEGATE_PTR) };
+    assert_eq!(ptr, 0);
+
+    // This would previously have called into the dropped delegate.
+    unsafe {
+        let _: () = msg_send![&*objc, viewWillAppear];
+    }
+
+    assert!(!CALLED.load(Ordering::SeqCst));
+
+    // And finally, release it.
+    drop(objc);
+}
@@ -50,6 +50,16 @@ extern "C" fn did_disappear<T: ViewDelegate>(this: &mut Object, _: Sel, animated
     controller.did_disappear(to_bool(animated));
 }
 
+/// Called when the view controller receives a `viewDidLayoutSubviews` message.
+extern "C" fn did_layout<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIViewController)), viewDidLayoutSubviews];
+    }
+
+    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    controller.did_layout();
+}
+
 /// Registers an `NSViewDelegate`.
 pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>(instance: &T) -> *const Class {
     load_or_register_class("UIViewController", instance.subclass_name(), |decl| unsafe {
@@ -65,5 +75,6 @@ pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>(instance
             sel!(viewDidDisappear:),
             did_disappear::<T> as extern "C" fn(&mut Object, _, BOOL)
         );
+        decl.add_method(sel!(viewDidLayoutSubviews), did_layout::<T> as extern "C" fn(&mut Object, _));
     })
 }
@@ -0,0 +1,13 @@
+/// Describes how a view should respond to hit testing; see `ViewDelegate::hit_test()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HitTestResult {
+    /// Use the default behavior, i.e whichever subview (or this view) contains the point.
+    Pass,
+
+    /// Act as if this view (and its subviews) don't contain the point, letting the event fall
+    /// through to whatever is underneath - e.g, for transparent overlay regions.
+    Ignore,
+
+    /// This view handles the point itself, even if a subview contains it.
+    Claim
+}
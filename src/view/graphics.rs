@@ -0,0 +1,61 @@
+//! A small wrapper around the Core Graphics context that AppKit sets up for custom drawing. See
+//! `ViewDelegate::draw()`.
+
+use std::fmt;
+
+use core_graphics::context::{CGContext, CGContextRef};
+use core_graphics::geometry::CGRect;
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::color::Color;
+use crate::foundation::{id, nil};
+use crate::geometry::Rect;
+
+/// Wraps the `CGContext` for the current drawing pass. This is only valid for the duration of the
+/// `draw()` call it's passed to, so don't hold on to it.
+///
+/// Only a few conveniences are provided for now; for anything else, drop down to the underlying
+/// context via `cg_context()`.
+pub struct GraphicsContext(CGContext);
+
+impl fmt::Debug for GraphicsContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphicsContext").finish()
+    }
+}
+
+impl GraphicsContext {
+    /// Returns the `CGContext` of the current `NSGraphicsContext`, if there is one.
+    pub(crate) fn current() -> Option<Self> {
+        unsafe {
+            let current: id = msg_send![class!(NSGraphicsContext), currentContext];
+
+            if current == nil {
+                return None;
+            }
+
+            let context_ptr: core_graphics::sys::CGContextRef = msg_send![current, CGContext];
+            Some(GraphicsContext(CGContext::from_existing_context_ptr(context_ptr)))
+        }
+    }
+
+    /// Sets the color used for subsequent fill operations.
+    pub fn set_fill_color<C: AsRef<Color>>(&self, color: C) {
+        self.0.set_fill_color(&color.as_ref().cg_color());
+    }
+
+    /// Fills `rect` (in the view's coordinate space) with `color`. This also sets the fill color
+    /// for subsequent operations.
+    pub fn fill_rect<C: AsRef<Color>>(&self, rect: Rect, color: C) {
+        let rect: CGRect = rect.into();
+
+        self.set_fill_color(color);
+        self.0.fill_rect(rect);
+    }
+
+    /// Returns the underlying Core Graphics context, for drawing that isn't wrapped here.
+    pub fn cg_context(&self) -> &CGContextRef {
+        &self.0
+    }
+}
@@ -40,21 +40,39 @@
 //! }
 //! ```
 //!
+//! To center a view rather than pinning its edges, use the `center_x` and `center_y` anchors
+//! (e.g, `self.red.center_x.constraint_equal_to(&self.content.center_x)`), which are available as
+//! soon as the view is created - `WebView` and the other widgets expose the same set.
+//!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
 use objc::runtime::{Class, Object};
-use objc::{msg_send, sel, sel_impl};
+use objc::{class, msg_send, sel, sel_impl};
 
 use crate::color::Color;
 use crate::foundation::{id, nil, NSArray, NSString, NO, YES};
-use crate::layer::Layer;
+use crate::image::Image;
+use crate::layer::{Gravity, Layer};
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
+use crate::utils::clear_ptr;
 use crate::utils::properties::ObjcProperty;
 
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY, SafeAreaLayoutGuide};
 
+#[cfg(feature = "appkit")]
+use std::cell::Cell;
+
+#[cfg(feature = "appkit")]
+use crate::accessibility::Role;
+
+#[cfg(feature = "appkit")]
+use crate::dragdrop::DragItem;
+
+#[cfg(feature = "appkit")]
+use crate::foundation::{Callback, DelegateContainer};
+
 #[cfg(feature = "appkit")]
 use crate::pasteboard::PasteboardType;
 
@@ -71,16 +89,32 @@ mod native_interface;
 mod controller;
 pub use controller::ViewController;
 
+#[cfg(feature = "appkit")]
+mod enums;
+
+#[cfg(feature = "appkit")]
+pub use enums::HitTestResult;
+
 #[cfg(feature = "appkit")]
 mod splitviewcontroller;
 
 #[cfg(feature = "appkit")]
 pub use splitviewcontroller::SplitViewController;
 
+#[cfg(feature = "appkit")]
+mod graphics;
+
+#[cfg(feature = "appkit")]
+pub use graphics::GraphicsContext;
+
 mod traits;
 pub use traits::ViewDelegate;
 
 pub(crate) static BACKGROUND_COLOR: &str = "cacaoBackgroundColor";
+pub(crate) static IS_FLIPPED: &str = "cacaoIsFlipped";
+pub(crate) static MOUSE_ENTERED_HANDLER: &str = "cacaoMouseEnteredHandler";
+pub(crate) static MOUSE_EXITED_HANDLER: &str = "cacaoMouseExitedHandler";
+pub(crate) static TRACKING_AREA: &str = "cacaoTrackingArea";
 pub(crate) static VIEW_DELEGATE_PTR: &str = "rstViewDelegatePtr";
 
 /// A clone-able handler to a `ViewController` reference in the Objective C runtime. We use this
@@ -167,6 +201,9 @@ impl View {
     ///
     /// This handles grabbing autolayout anchor pointers, as well as things related to layering and
     /// so on. It returns a generic `View<T>`, which the caller can then customize as needed.
+    ///
+    /// `view` must already be retained (e.g, from `new`, or `alloc`/`init`); the returned value
+    /// takes over that ownership rather than retaining it again.
     pub(crate) fn init<T>(view: id) -> View<T> {
         unsafe {
             #[cfg(feature = "autolayout")]
@@ -174,6 +211,9 @@ impl View {
 
             #[cfg(feature = "appkit")]
             let _: () = msg_send![view, setWantsLayer: YES];
+
+            #[cfg(feature = "appkit")]
+            (&mut *view).set_ivar(IS_FLIPPED, YES);
         }
 
         View {
@@ -213,11 +253,11 @@ impl View {
             #[cfg(feature = "autolayout")]
             center_y: LayoutAnchorY::center(view),
 
-            layer: Layer::wrap(unsafe { msg_send![view, layer] }),
+            layer: Layer::from_unretained(unsafe { msg_send![view, layer] }),
 
             #[cfg(all(feature = "appkit", target_os = "macos"))]
             animator: ViewAnimatorProxy::new(view),
-            objc: ObjcProperty::retain(view)
+            objc: ObjcProperty::from_retained(view)
         }
     }
 
@@ -225,6 +265,42 @@ impl View {
     pub fn new() -> Self {
         View::init(unsafe { msg_send![native_interface::register_view_class(), new] })
     }
+
+    /// Runs `changes`, animating any animatable view or layer properties they modify (e.g, frame,
+    /// alpha, background color, corner radius) over `duration` seconds.
+    ///
+    /// This is a synchronous `NSAnimationContext` group with implicit animation enabled; changes
+    /// made through `animator` are animated as well.
+    #[cfg(feature = "appkit")]
+    pub fn with_animation<F: FnOnce()>(duration: f64, changes: F) {
+        unsafe {
+            let _: () = msg_send![class!(NSAnimationContext), beginGrouping];
+            let context: id = msg_send![class!(NSAnimationContext), currentContext];
+            let _: () = msg_send![context, setDuration: duration];
+            let _: () = msg_send![context, setAllowsImplicitAnimation: YES];
+        }
+
+        changes();
+
+        unsafe {
+            let _: () = msg_send![class!(NSAnimationContext), endGrouping];
+        }
+    }
+
+    /// Runs `changes` with implicit layer animations disabled, so that any layer properties they
+    /// modify (e.g, background color, corner radius) update immediately rather than fading.
+    pub fn without_animation<F: FnOnce()>(changes: F) {
+        unsafe {
+            let _: () = msg_send![class!(CATransaction), begin];
+            let _: () = msg_send![class!(CATransaction), setDisableActions: YES];
+        }
+
+        changes();
+
+        unsafe {
+            let _: () = msg_send![class!(CATransaction), commit];
+        }
+    }
 }
 
 impl<T> View<T>
@@ -246,6 +322,11 @@ where
         };
 
         let mut view = View::init(view);
+
+        if let Some(color) = delegate.default_background_color() {
+            view.set_background_color(color);
+        }
+
         (&mut delegate).did_load(view.clone_as_handle());
         view.delegate = Some(delegate);
         view
@@ -303,6 +384,10 @@ impl<T> View<T> {
     }
 
     /// Call this to set the background color for the backing layer.
+    ///
+    /// On AppKit, the color is applied whenever the system asks the view to update its layer, so
+    /// dynamic colors will follow appearance changes. If you need the color on the layer
+    /// immediately instead, see `Layout::set_layer_background_color()`.
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         let color: id = color.as_ref().into();
 
@@ -316,6 +401,188 @@ impl<T> View<T> {
             let _: () = msg_send![&*obj, setBackgroundColor: color];
         });
     }
+
+    /// Rounds the corners of this view (all four), via its backing layer.
+    ///
+    /// On AppKit, this (and the other layer styling methods) makes the view layer-backed. That's
+    /// the default for `View`, but note that a layer-backed view makes its subviews layer-backed
+    /// too, which can change how they render - e.g, custom drawing is cached into the layer, and
+    /// subviews are clipped differently.
+    pub fn set_corner_radius(&self, radius: f64) {
+        self.ensure_layer();
+        self.layer.set_corner_radius(radius);
+    }
+
+    /// Sets the width of the border drawn around this view. See `set_corner_radius()` for notes on
+    /// layer-backing.
+    pub fn set_border_width(&self, width: f64) {
+        self.ensure_layer();
+        self.layer.set_border_width(width);
+    }
+
+    /// Sets the color of the border drawn around this view. See `Layer::set_border_color()`.
+    pub fn set_border_color<C: AsRef<Color>>(&self, color: C) {
+        self.ensure_layer();
+        self.layer.set_border_color(color);
+    }
+
+    /// Configures a drop shadow for this view. See `Layer::set_shadow()` for the details - in
+    /// particular, shadows aren't visible on layers that clip to their bounds.
+    pub fn set_shadow<C: AsRef<Color>>(&self, radius: f64, opacity: f32, offset: (f64, f64), color: C) {
+        self.ensure_layer();
+        self.layer.set_shadow(radius, opacity, offset, color);
+    }
+
+    /// Sets the backing layer's contents to the given image - e.g, for a decorative background.
+    /// This is cheaper than adding an `ImageView` subview. See `Layer::set_contents()`.
+    pub fn set_layer_contents(&self, image: &Image) {
+        self.ensure_layer();
+        self.layer.set_contents(image);
+    }
+
+    /// Sets how the backing layer's contents are positioned or scaled. See
+    /// `set_layer_contents()`.
+    pub fn set_layer_contents_gravity(&self, gravity: Gravity) {
+        self.layer.set_contents_gravity(gravity);
+    }
+
+    /// Runs `changes` - typically a batch of constraint updates - and then performs a single
+    /// layout pass for this view and its subviews.
+    ///
+    /// AppKit and UIKit already coalesce layout until the next pass of the run loop, so the thing
+    /// to avoid is forcing layout (e.g, reading a frame) partway through a batch. Making your
+    /// changes in here, ideally via `LayoutConstraint::activate()`/`deactivate()` with slices of
+    /// constraints, ensures that layout is resolved exactly once, when `changes` returns. Layer
+    /// updates made in `changes` are committed in the same `CATransaction`.
+    pub fn perform_batch_layout<F: FnOnce()>(&self, changes: F) {
+        unsafe {
+            let _: () = msg_send![class!(CATransaction), begin];
+        }
+
+        changes();
+
+        self.set_needs_layout();
+        self.layout_if_needed();
+
+        unsafe {
+            let _: () = msg_send![class!(CATransaction), commit];
+        }
+    }
+
+    /// Ensures this view is layer-backed before we configure its layer. This is already the case
+    /// for views we create, but not necessarily for ones that have been reconfigured since.
+    fn ensure_layer(&self) {
+        #[cfg(feature = "appkit")]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setWantsLayer: YES];
+        });
+    }
+
+    /// Sets whether this view uses a flipped coordinate system. Views default to being flipped,
+    /// i.e, the origin is in the top-left (as on iOS and the web); pass `false` to use AppKit's
+    /// standard bottom-left origin instead.
+    ///
+    /// `isFlipped` isn't settable in AppKit, so this is stored on the view and returned from our
+    /// subclass' override. Subview frames are interpreted according to this, so you'll generally
+    /// want to set it before adding and positioning subviews.
+    #[cfg(feature = "appkit")]
+    pub fn set_flipped(&self, flipped: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            (&mut *obj).set_ivar(IS_FLIPPED, match flipped {
+                true => YES,
+                false => NO
+            });
+
+            let _: () = msg_send![obj, setNeedsLayout: YES];
+        });
+    }
+
+    /// Sets the label that assistive technologies (e.g, VoiceOver) read for this view. Custom-drawn
+    /// views should always set this, as they're otherwise invisible to assistive technologies.
+    ///
+    /// On AppKit, this also marks the view as an accessibility element.
+    pub fn set_accessibility_label(&self, label: &str) {
+        let label = NSString::new(label);
+
+        self.objc.with_mut(|obj| unsafe {
+            #[cfg(feature = "appkit")]
+            let _: () = msg_send![obj, setAccessibilityElement: YES];
+
+            let _: () = msg_send![obj, setAccessibilityLabel:&*label];
+        });
+    }
+
+    /// Sets the value that assistive technologies read for this view - e.g, the current position
+    /// of a custom slider, or the state of a custom toggle.
+    pub fn set_accessibility_value(&self, value: &str) {
+        let value = NSString::new(value);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAccessibilityValue:&*value];
+        });
+    }
+
+    /// Sets the role of this view, which determines how assistive technologies present it and
+    /// interact with it. This also marks the view as an accessibility element.
+    #[cfg(feature = "appkit")]
+    pub fn set_accessibility_role(&self, role: Role) {
+        let role: NSString = role.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAccessibilityElement: YES];
+            let _: () = msg_send![obj, setAccessibilityRole:&*role];
+        });
+    }
+
+    /// Calls `handler` whenever the mouse enters this view. Calling this again replaces any
+    /// existing handler.
+    ///
+    /// This installs an `NSTrackingArea` covering the view's bounds, which is recreated whenever
+    /// AppKit asks the view to update its tracking areas (e.g, on resize). Events are only
+    /// delivered while the application is active.
+    #[cfg(feature = "appkit")]
+    pub fn on_mouse_entered<F: Fn() + 'static>(&self, handler: F) {
+        self.set_mouse_handler(MOUSE_ENTERED_HANDLER, handler);
+    }
+
+    /// Calls `handler` whenever the mouse exits this view. Calling this again replaces any
+    /// existing handler. See `on_mouse_entered()` for details on tracking.
+    #[cfg(feature = "appkit")]
+    pub fn on_mouse_exited<F: Fn() + 'static>(&self, handler: F) {
+        self.set_mouse_handler(MOUSE_EXITED_HANDLER, handler);
+    }
+
+    /// Stores `handler` in the given ivar (dropping any existing one), and refreshes the tracking
+    /// area so events start flowing.
+    #[cfg(feature = "appkit")]
+    fn set_mouse_handler<F: Fn() + 'static>(&self, ivar: &'static str, handler: F) {
+        let handler: Cell<Option<Callback>> = Cell::new(Some(Box::new(handler)));
+
+        self.objc.with_mut(|obj| unsafe {
+            if let Some(handler) = handler.take() {
+                DelegateContainer::set(&mut *obj, ivar, handler);
+            }
+
+            let _: () = msg_send![obj, updateTrackingAreas];
+        });
+    }
+
+    /// Begins a drag session with this view as the source, carrying the provided items. Call this
+    /// while handling the mouse event that should start the drag (e.g, a mouse-down or
+    /// mouse-dragged event), as AppKit uses the current event to position and track the drag.
+    ///
+    /// The supported operations are determined by `ViewDelegate::dragging_source_operation()`,
+    /// and default to `DragOperation::Copy` for views without a delegate.
+    #[cfg(feature = "appkit")]
+    pub fn begin_dragging_session(&self, items: &[DragItem]) {
+        let items: NSArray = items.iter().map(|item| &*item.objc).collect::<Vec<&Object>>().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let event: id = msg_send![app, currentEvent];
+            let _: id = msg_send![obj, beginDraggingSessionWithItems:&*items event:event source:obj];
+        });
+    }
 }
 
 impl<T> ObjcAccess for View<T> {
@@ -339,9 +606,108 @@ impl<T> Drop for View<T> {
     ///
     /// If you're venturing into unsafe code for the sake of custom behavior via the Objective-C
     /// runtime, you can consider flagging your instance as a handle - it will avoid the drop logic here.
+    ///
+    /// We also clear the delegate pointer on the Objective-C side, as the view may outlive us
+    /// (e.g, if something else has retained it), and callbacks shouldn't reach a dropped delegate.
     fn drop(&mut self) {
         if !self.is_handle {
+            if self.delegate.is_some() {
+                self.objc.with_mut(|obj| clear_ptr(obj, VIEW_DELEGATE_PTR));
+            }
+
             self.remove_from_superview();
         }
     }
 }
+
+#[test]
+#[cfg(all(feature = "appkit", target_os = "macos"))]
+fn test_flipped_subview_at_zero_is_at_top() {
+    use core_graphics::geometry::CGRect;
+
+    use crate::geometry::Rect;
+
+    let container = View::new();
+    container.set_flipped(false);
+    container.set_frame(Rect::new(0., 0., 100., 100.));
+
+    let view = View::new();
+    view.set_frame(Rect::new(0., 0., 100., 100.));
+    container.add_subview(&view);
+
+    let subview = View::new();
+    subview.set_frame(Rect::new(0., 0., 10., 10.));
+    view.add_subview(&subview);
+
+    // Convert into the (unflipped) container, where the top edge sits at y = 100.
+    let frame: CGRect = unsafe {
+        let frame: CGRect = subview.objc.get(|obj| msg_send![obj, frame]);
+        view.objc
+            .get(|obj| container.objc.get(|c| msg_send![obj, convertRect:frame toView:c]))
+    };
+
+    assert_eq!(frame.origin.y, 90.);
+
+    // And once unflipped, the same frame should sit at the bottom.
+    view.set_flipped(false);
+
+    let frame: CGRect = unsafe {
+        let frame: CGRect = subview.objc.get(|obj| msg_send![obj, frame]);
+        view.objc
+            .get(|obj| container.objc.get(|c| msg_send![obj, convertRect:frame toView:c]))
+    };
+
+    assert_eq!(frame.origin.y, 0.);
+}
+
+#[test]
+#[cfg(all(feature = "appkit", target_os = "macos"))]
+fn test_dragged_types_registered_before_adding_to_superview() {
+    let view = View::new();
+    view.register_for_dragged_types(&[PasteboardType::FileURL, PasteboardType::String]);
+
+    let container = View::new();
+    container.add_subview(&view);
+
+    let registered = view
+        .objc
+        .get(|obj| NSArray::retain(unsafe { msg_send![obj, registeredDraggedTypes] }).count());
+
+    assert_eq!(registered, 2);
+}
+
+#[test]
+#[cfg(all(feature = "appkit", target_os = "macos"))]
+fn test_delegate_callbacks_stop_after_view_is_dropped() {
+    use objc_id::ShareId;
+
+    use crate::geometry::{Point, Rect};
+    use crate::utils::CGPoint;
+
+    struct IgnoreAll;
+
+    impl ViewDelegate for IgnoreAll {
+        const NAME: &'static str = "TestIgnoreAllHitTestsView";
+
+        fn hit_test(&self, _point: Point) -> HitTestResult {
+            HitTestResult::Ignore
+        }
+    }
+
+    // The delegate is moved into the view; there's no other binding to it.
+    let view = View::with(IgnoreAll);
+    view.set_frame(Rect::new(0., 0., 100., 100.));
+
+    let objc: ShareId<Object> = view.objc.get(|obj| unsafe { ShareId::from_ptr(obj as *const Object as id) });
+    let point = CGPoint::new(50., 50.);
+
+    let hit: id = unsafe { msg_send![&*objc, hitTest: point] };
+    assert_eq!(hit, nil);
+
+    // The Objective-C view outlives the `View`; hit testing should fall back to the default
+    // behavior rather than calling into the dropped delegate.
+    drop(view);
+
+    let hit: id = unsafe { msg_send![&*objc, hitTest: point] };
+    assert_eq!(hit, &*objc as *const Object as id);
+}
@@ -1,10 +1,23 @@
 //! Various traits used for Views.
 
+#[cfg(feature = "appkit")]
+use crate::appkit::ScrollEvent;
+
 #[cfg(feature = "appkit")]
 use crate::dragdrop::{DragInfo, DragOperation};
 
+#[cfg(feature = "appkit")]
+use crate::pasteboard::Pasteboard;
+
+use crate::color::Color;
 use crate::view::View;
 
+#[cfg(feature = "appkit")]
+use crate::geometry::{Point, Rect};
+
+#[cfg(feature = "appkit")]
+use crate::view::{GraphicsContext, HitTestResult};
+
 /// This trait can be used for implementing custom View behavior. You implement this trait on your
 /// struct, and wrap your struct in a `View` or `ViewController`. The view or controller then
 /// handles interfacing between your struct and system events.
@@ -24,6 +37,13 @@ pub trait ViewDelegate {
         Self::NAME
     }
 
+    /// The background color to apply when the view is created, before `did_load()` is called.
+    /// Useful for apps with a consistent theme; returning `None` (the default) leaves the
+    /// background as-is.
+    fn default_background_color(&self) -> Option<Color> {
+        None
+    }
+
     /// Called when the View is ready to work with. You're passed a `View` - this is safe to
     /// store and use repeatedly, but it's not thread safe - any UI calls must be made from the
     /// main thread!
@@ -41,6 +61,26 @@ pub trait ViewDelegate {
     /// Called when this has been removed from the view heirarchy.
     fn did_disappear(&self, animated: bool) {}
 
+    /// Called after the view has laid out its subviews - i.e, after Auto Layout has run. This is
+    /// the place to read final frames, or to position anything that can't be expressed with
+    /// constraints. Only called when this is used with a `ViewController`.
+    fn did_layout(&self) {}
+
+    /// Return `true` to opt this view into custom drawing via `draw()`. Views otherwise render
+    /// purely through their layer, which is cheaper, so this defaults to `false`.
+    #[cfg(feature = "appkit")]
+    fn draws_content(&self) -> bool {
+        false
+    }
+
+    /// Called to draw this view's content, if `draws_content()` returns `true`. `dirty_rect` is
+    /// the portion of the view (in its own coordinate space) that needs redrawing; to trigger a
+    /// redraw, call `set_needs_display(true)` on the view.
+    ///
+    /// The background color (if set) has already been filled in by the time this is called.
+    #[cfg(feature = "appkit")]
+    fn draw(&self, context: &GraphicsContext, dirty_rect: Rect) {}
+
     /// Invoked when the dragged image enters destination bounds or frame; returns dragging
     /// operation to perform.
     #[cfg(feature = "appkit")]
@@ -48,6 +88,14 @@ pub trait ViewDelegate {
         DragOperation::None
     }
 
+    /// Invoked periodically as the dragged image moves within the destination; returns the
+    /// dragging operation to perform. Defaults to whatever `dragging_entered` returns, which is
+    /// what AppKit does when this isn't implemented.
+    #[cfg(feature = "appkit")]
+    fn dragging_updated(&self, info: DragInfo) -> DragOperation {
+        self.dragging_entered(info)
+    }
+
     /// Invoked when the image is released, allowing the receiver to agree to or refuse
     /// drag operation.
     #[cfg(feature = "appkit")]
@@ -62,6 +110,17 @@ pub trait ViewDelegate {
         false
     }
 
+    /// Invoked when something has been dropped on this view, with the pasteboard holding the
+    /// dropped content (see `Pasteboard::read_strings()` and `Pasteboard::read_file_urls()`).
+    /// Return `true` if you accepted the drop.
+    ///
+    /// This is a simpler alternative to `perform_drag_operation()`, and is only called if that
+    /// returns `false` (the default).
+    #[cfg(feature = "appkit")]
+    fn dropped(&self, pasteboard: &Pasteboard) -> bool {
+        false
+    }
+
     /// Invoked when the dragging operation is complete, signaling the receiver to perform
     /// any necessary clean-up.
     #[cfg(feature = "appkit")]
@@ -72,5 +131,52 @@ pub trait ViewDelegate {
     #[cfg(feature = "appkit")]
     fn dragging_exited(&self, info: DragInfo) {}
 
+    /// Called when this view receives a scroll wheel (or trackpad scroll) event. Return `true`
+    /// if you handled it; returning `false` passes it on to the default implementation, which
+    /// forwards it up the responder chain (e.g, to an enclosing scroll view).
+    #[cfg(feature = "appkit")]
+    fn scroll_wheel(&self, event: ScrollEvent) -> bool {
+        false
+    }
+
+    /// Called when determining whether a mouse event at `point` (in this view's coordinate space)
+    /// belongs to this view. This enables non-rectangular interactive regions (e.g, a circular
+    /// button), or overlays that let events pass through in places. The default, `Pass`, uses
+    /// standard hit testing.
+    #[cfg(feature = "appkit")]
+    fn hit_test(&self, point: Point) -> HitTestResult {
+        HitTestResult::Pass
+    }
+
+    /// Invoked when a drag that this view began (see `View::begin_dragging_session()`) needs to
+    /// know which operations it supports. `within_application` indicates whether the drag is
+    /// currently over a destination in this application, or outside of it.
+    #[cfg(feature = "appkit")]
+    fn dragging_source_operation(&self, within_application: bool) -> DragOperation {
+        DragOperation::Copy
+    }
+
+    /// Called when a standard action (e.g, from the Edit menu, or its keyboard shortcut) is sent
+    /// to a `ViewController` wrapping this delegate. `action` is the selector name - one of
+    /// `cut:`, `copy:`, `paste:`, `delete:`, or `selectAll:`.
+    ///
+    /// Return `true` if you handled the action. Returning `false` passes it further along the
+    /// responder chain.
+    #[cfg(feature = "appkit")]
+    fn perform_action(&self, action: &str) -> bool {
+        false
+    }
+
+    /// Called when the system is validating a menu item (e.g, deciding whether Edit > Copy should
+    /// be enabled) that would route one of the actions described in `perform_action()` to a
+    /// `ViewController` wrapping this delegate.
+    ///
+    /// Return `true` if you can currently perform `action`. Returning `false` defers to the rest
+    /// of the responder chain.
+    #[cfg(feature = "appkit")]
+    fn can_perform_action(&self, action: &str) -> bool {
+        false
+    }
+
     //fn perform_key_equivalent(&self, event: Event) -> bool { false }
 }
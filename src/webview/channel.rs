@@ -0,0 +1,71 @@
+//! Implements a `WKScriptMessageHandler` that pushes incoming messages into a channel, rather than
+//! calling through to a delegate. See `WebViewConfig::add_message_channel()`.
+
+use std::sync::mpsc::Sender;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, load_or_register_class, DelegateContainer, NSString};
+
+pub(crate) static CHANNEL_SENDER_PTR: &str = "rstWebViewChannelSenderPtr";
+
+/// A message posted from JavaScript via `window.webkit.messageHandlers.<name>.postMessage(body)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsMessage {
+    /// The name of the handler the message was posted to.
+    pub name: String,
+
+    /// The message body. Strings come through as-is; for anything else, you're best off calling
+    /// `JSON.stringify()` on the JavaScript side, as other values are passed through
+    /// `[NSObject description]`.
+    pub body: String
+}
+
+/// Fires when a message has been passed from the underlying `WKWebView`. We're always on the main
+/// thread here, so all we do is convert and enqueue.
+extern "C" fn on_message(this: &Object, _: Sel, _: id, script_message: id) {
+    let sender = match unsafe { DelegateContainer::<Sender<JsMessage>>::get(this, CHANNEL_SENDER_PTR) } {
+        Some(sender) => sender,
+        None => return
+    };
+
+    let message = unsafe {
+        let name = NSString::retain(msg_send![script_message, name]);
+        let body: id = msg_send![script_message, body];
+
+        let body = match NSString::is(body) {
+            true => NSString::retain(body).to_string(),
+            false => NSString::retain(msg_send![body, description]).to_string()
+        };
+
+        JsMessage {
+            name: name.to_string(),
+            body
+        }
+    };
+
+    // If the receiver has been dropped, nobody is listening anymore - which is fine.
+    let _ = sender.send(message);
+}
+
+/// Drops the `Sender` we're holding when the handler is deallocated, which will in turn let the
+/// receiving side know that no further messages are coming.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        DelegateContainer::<Sender<JsMessage>>::clear(this, CHANNEL_SENDER_PTR);
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers an `NSObject` subclass that acts as a `WKScriptMessageHandler`, holding a pointer to
+/// a `Sender` in an ivar.
+pub(crate) fn register_channel_handler_class() -> *const Class {
+    load_or_register_class("NSObject", "RSTWebViewMessageChannel", |decl| unsafe {
+        decl.add_ivar::<usize>(CHANNEL_SENDER_PTR);
+
+        decl.add_method2(sel!(userContentController:didReceiveScriptMessage:), on_message);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, _));
+    })
+}
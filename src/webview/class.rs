@@ -12,11 +12,12 @@ use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 
+use crate::error::Error;
 use crate::foundation::{id, load_or_register_class, nil, NSArray, NSInteger, NSString, NO, YES};
 use crate::webview::actions::{NavigationAction, NavigationResponse};
-use crate::webview::{mimetype::MimeType, WebViewDelegate, WEBVIEW_DELEGATE_PTR}; //, OpenPanelParameters};
-                                                                                 //use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
-use crate::utils::load;
+use crate::webview::{mimetype::MimeType, JsValue, WebViewDelegate, WEBVIEW_DELEGATE_PTR}; //, OpenPanelParameters};
+                                                                                          //use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
+use crate::utils::load_opt;
 
 /// Called when an `alert()` from the underlying `WKWebView` is fired. Will call over to your
 /// `WebViewController`, where you should handle the event.
@@ -39,24 +40,41 @@ extern "C" fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id,
     /*let queue = dispatch::Queue::main();
     queue.exec_async(move || {
         let a = Alert::new("Subatomic", message);
-        a.show();
+        a.run_modal();
     });*/
 }
 
 /// Fires when a message has been passed from the underlying `WKWebView`.
 extern "C" fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_message: id) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
-
-    unsafe {
-        let name = NSString::from_retained(msg_send![script_message, name]);
-        let body = NSString::retain(msg_send![script_message, body]);
-        delegate.on_message(name.to_str(), body.to_str());
-    }
+    let delegate = match load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
+
+    let (name, body) = unsafe {
+        let name = NSString::retain(msg_send![script_message, name]);
+        let body: id = msg_send![script_message, body];
+        (name, body)
+    };
+
+    // `on_message()` predates structured bodies: strings pass through as-is, and anything else
+    // is passed along via `[NSObject description]`.
+    let text = match body {
+        b if b == nil => String::new(),
+        b if NSString::is(b) => NSString::retain(b).to_string(),
+        b => NSString::retain(unsafe { msg_send![b, description] }).to_string()
+    };
+
+    delegate.on_message(name.to_str(), &text);
+    delegate.did_receive_message(name.to_str(), JsValue::from_objc(body));
 }
 
 /// Fires when a custom protocol URI is requested from the underlying `WKWebView`.
 extern "C" fn start_url_scheme_task<T: WebViewDelegate>(this: &Object, _: Sel, _webview: id, task: id) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     unsafe {
         let request: id = msg_send![task, request];
@@ -89,7 +107,10 @@ extern "C" fn stop_url_scheme_task<T: WebViewDelegate>(_: &Object, _: Sel, _webv
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern "C" fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _: id, action: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     let action = NavigationAction::new(action);
 
@@ -101,7 +122,10 @@ extern "C" fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern "C" fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel, _: id, response: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     let response = NavigationResponse::new(response);
 
@@ -111,9 +135,41 @@ extern "C" fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: S
     });
 }
 
+/// Fires when a navigation has started loading.
+extern "C" fn did_start_navigation<T: WebViewDelegate>(this: &Object, _: Sel, _: id, _: id) {
+    if let Some(delegate) = load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.did_start_navigation();
+    }
+}
+
+/// Fires when a navigation has finished loading.
+extern "C" fn did_finish_navigation<T: WebViewDelegate>(this: &Object, _: Sel, _: id, _: id) {
+    if let Some(delegate) = load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.did_finish_navigation();
+    }
+}
+
+/// Fires when a navigation has failed - used for both provisional (i.e, before any content has
+/// been received) and committed navigations.
+extern "C" fn did_fail_navigation<T: WebViewDelegate>(this: &Object, _: Sel, _: id, _: id, error: id) {
+    if let Some(delegate) = load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.did_fail_navigation(Error::new(error));
+    }
+}
+
+/// Fires when the web content process has been terminated, leaving the view blank.
+extern "C" fn web_content_process_did_terminate<T: WebViewDelegate>(this: &Object, _: Sel, _: id) {
+    if let Some(delegate) = load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.web_content_process_did_terminate();
+    }
+}
+
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern "C" fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, params: id, _: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     delegate.run_open_panel(params.into(), move |urls| unsafe {
         let handler = handler as *const Block<(id,), c_void>;
@@ -144,7 +200,10 @@ extern "C" fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, p
 /// API.
 #[cfg(feature = "webview-downloading-macos")]
 extern "C" fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: id, suggested_filename: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_opt::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     let handler = handler as *const Block<(objc::runtime::BOOL, id), c_void>;
     let filename = NSString::from_retained(suggested_filename);
@@ -199,6 +258,26 @@ pub fn register_webview_delegate_class<T: WebViewDelegate>(instance: &T) -> *con
             sel!(webView:decidePolicyForNavigationResponse:decisionHandler:),
             decide_policy_for_response::<T> as extern "C" fn(&Object, _, _, id, usize)
         );
+        decl.add_method(
+            sel!(webView:didStartProvisionalNavigation:),
+            did_start_navigation::<T> as extern "C" fn(&Object, _, _, _)
+        );
+        decl.add_method(
+            sel!(webView:didFinishNavigation:),
+            did_finish_navigation::<T> as extern "C" fn(&Object, _, _, _)
+        );
+        decl.add_method(
+            sel!(webView:didFailNavigation:withError:),
+            did_fail_navigation::<T> as extern "C" fn(&Object, _, _, _, id)
+        );
+        decl.add_method(
+            sel!(webView:didFailProvisionalNavigation:withError:),
+            did_fail_navigation::<T> as extern "C" fn(&Object, _, _, _, id)
+        );
+        decl.add_method(
+            sel!(webViewWebContentProcessDidTerminate:),
+            web_content_process_did_terminate::<T> as extern "C" fn(&Object, _, _)
+        );
 
         // WKScriptMessageHandler
         decl.add_method(
@@ -1,11 +1,20 @@
 //! A wrapper for `WKWebViewConfiguration`. It aims to (mostly) cover
 //! the important pieces of configuring and updating a WebView configuration.
 
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::mpsc::{channel, Receiver};
+
+use block::ConcreteBlock;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
-use crate::foundation::{id, NSInteger, NSString, NO, YES};
+use crate::foundation::kvc::{self, KvcValue};
+use crate::foundation::{id, DelegateContainer, NSInteger, NSString, NO, YES};
+use crate::webview::channel::{register_channel_handler_class, JsMessage, CHANNEL_SENDER_PTR};
+use crate::webview::content_world::ContentWorld;
+use crate::webview::cookie::Cookie;
 use crate::webview::enums::InjectAt;
 
 /// A wrapper for `WKWebViewConfiguration`. Holds (retains) pointers for the Objective-C runtime
@@ -14,7 +23,10 @@ use crate::webview::enums::InjectAt;
 pub struct WebViewConfig {
     pub objc: Id<Object>,
     pub handlers: Vec<String>,
-    pub protocols: Vec<String>
+    pub protocols: Vec<String>,
+
+    /// Whether the page context menu (e.g, right click) is available. Defaults to `true`.
+    pub allows_context_menu: bool
 }
 
 impl Default for WebViewConfig {
@@ -28,32 +40,127 @@ impl Default for WebViewConfig {
         WebViewConfig {
             objc: config,
             handlers: vec![],
-            protocols: vec![]
+            protocols: vec![],
+            allows_context_menu: true
         }
     }
 }
 
 impl WebViewConfig {
     /// Pushes the specified handler name onto the stack, queuing it for initialization with the
-    /// `WKWebView`.
+    /// `WKWebView`. Messages posted to it (via `window.webkit.messageHandlers.<name>.postMessage()`)
+    /// are passed to `WebViewDelegate::on_message()` and `WebViewDelegate::did_receive_message()`.
     pub fn add_handler(&mut self, name: &str) {
         self.handlers.push(name.to_string());
     }
 
+    /// Registers a script message handler with the given name that, rather than calling through
+    /// to your `WebViewDelegate`, pushes each message into a channel. This is useful for bridging
+    /// to a background thread or async task, which can `recv()` from the returned `Receiver`.
+    ///
+    /// The handler still fires on the main thread, but does nothing beyond enqueueing. The sending
+    /// side is dropped (and the channel closed) once the `WKWebView` and its configuration are
+    /// deallocated.
+    ///
+    /// Unlike `add_handler()`, this does not require a `WebViewDelegate`.
+    pub fn add_message_channel(&mut self, name: &str) -> Receiver<JsMessage> {
+        let (sender, receiver) = channel();
+        let name = NSString::new(name);
+
+        unsafe {
+            let handler: id = msg_send![register_channel_handler_class(), new];
+            DelegateContainer::set(&mut *handler, CHANNEL_SENDER_PTR, sender);
+
+            // The user content controller retains the handler, so we can let go of it here.
+            let content_controller: id = msg_send![&*self.objc, userContentController];
+            let _: () = msg_send![content_controller, addScriptMessageHandler:handler name:&*name];
+            let _: () = msg_send![handler, release];
+        }
+
+        receiver
+    }
+
     /// Adds the given user script to the underlying `WKWebView` user content controller.
+    ///
+    /// Scripts injected at `InjectAt::Start` run after the document element is created, but
+    /// before any of the page's own scripts - which makes this the place for shims and polyfills.
+    /// These are attached to the configuration, so they apply to any `WebView` created with it; to
+    /// add scripts to an existing view, see `WebView::add_user_script_now()`.
     pub fn add_user_script(&mut self, script: &str, at: InjectAt, main_frame_only: bool) {
-        let source = NSString::new(script);
-        let at: NSInteger = at.into();
+        let content_controller: id = unsafe { msg_send![&*self.objc, userContentController] };
+        add_user_script(content_controller, script, at, main_frame_only, None);
+    }
+
+    /// Adds the given user script to the underlying `WKWebView` user content controller, running
+    /// it in the given content world - e.g, to keep a bridge script isolated from page scripts.
+    ///
+    /// Content worlds require macOS 11 (iOS 14); on older systems, the script is injected into
+    /// the page world.
+    pub fn add_user_script_in_world(&mut self, script: &str, at: InjectAt, main_frame_only: bool, world: ContentWorld) {
+        let content_controller: id = unsafe { msg_send![&*self.objc, userContentController] };
+        add_user_script(content_controller, script, at, main_frame_only, Some(&world));
+    }
 
+    /// Swaps this configuration's data store for a non-persistent one, so that nothing (cookies,
+    /// caches, local storage, and so on) is written to disk - akin to a private browsing window.
+    ///
+    /// This must be called before the configuration is passed to `WebView::new()`; the data store
+    /// can't be changed once the `WKWebView` has been created. If you're also calling
+    /// `add_initial_cookies()`, call this first, as cookies go into whichever store is current.
+    pub fn set_non_persistent_data_store(&mut self) {
         unsafe {
-            let alloc: id = msg_send![class!(WKUserScript), alloc];
-            let user_script: id = msg_send![alloc, initWithSource:source injectionTime:at forMainFrameOnly:match main_frame_only {
-                true => YES,
-                false => NO
-            }];
+            let data_store: id = msg_send![class!(WKWebsiteDataStore), nonPersistentDataStore];
+            let _: () = msg_send![&*self.objc, setWebsiteDataStore: data_store];
+        }
+    }
 
-            let content_controller: id = msg_send![&*self.objc, userContentController];
-            let _: () = msg_send![content_controller, addUserScript: user_script];
+    /// Sets the given cookies on this configuration's data store, so that they're present for the
+    /// first navigation - e.g, for single sign-on. `ready` is called (on the main thread) once
+    /// every cookie has been stored, or immediately if `cookies` is empty.
+    ///
+    /// Setting cookies is asynchronous, and the `WKWebView` doesn't wait on it: if you call
+    /// `load_url()` right after creating the `WebView`, the request may go out before the cookies
+    /// land. Kick off your initial load from `ready` instead (e.g, by message passing to wherever
+    /// your `WebView` lives).
+    pub fn add_initial_cookies<F: Fn() + 'static>(&mut self, cookies: &[Cookie], ready: F) {
+        if cookies.is_empty() {
+            ready();
+            return;
+        }
+
+        let remaining = Rc::new(Cell::new(cookies.len()));
+        let ready: Rc<dyn Fn()> = Rc::new(ready);
+
+        // Counts down as each cookie is stored (or skipped), firing `ready` after the last one.
+        let finish_one = move |remaining: &Cell<usize>, ready: &dyn Fn()| {
+            remaining.set(remaining.get() - 1);
+
+            if remaining.get() == 0 {
+                ready();
+            }
+        };
+
+        unsafe {
+            let data_store: id = msg_send![&*self.objc, websiteDataStore];
+            let cookie_store: id = msg_send![data_store, httpCookieStore];
+
+            for cookie in cookies {
+                // Invalid cookies (e.g, a missing name or domain) can't be stored; skip them
+                // rather than leaving `ready` waiting on a completion that'll never come.
+                let objc_cookie = cookie.to_objc();
+                if objc_cookie.is_null() {
+                    finish_one(&remaining, &*ready);
+                    continue;
+                }
+
+                let remaining = remaining.clone();
+                let ready = ready.clone();
+
+                let completion = ConcreteBlock::new(move || finish_one(&remaining, &*ready));
+                let completion = completion.copy();
+
+                let _: () = msg_send![cookie_store, setCookie:objc_cookie completionHandler:&*completion];
+            }
         }
     }
 
@@ -63,15 +170,21 @@ impl WebViewConfig {
         self.protocols.push(protocol_name.to_string());
     }
 
-    /// Enables access to the underlying inspector view for `WKWebView`.
-    pub fn enable_developer_extras(&mut self) {
-        let key = NSString::new("developerExtrasEnabled");
+    /// Sets whether the page context menu (e.g, on right click) can be opened. Defaults to `true`.
+    ///
+    /// There's no public API for this on macOS, so when disabled, a user script is injected into
+    /// every frame at document start that cancels the `contextmenu` event.
+    pub fn set_allows_context_menu(&mut self, allows: bool) {
+        self.allows_context_menu = allows;
+    }
 
-        unsafe {
-            let yes: id = msg_send![class!(NSNumber), numberWithBool: YES];
-            let preferences: id = msg_send![&*self.objc, preferences];
-            let _: () = msg_send![preferences, setValue:yes forKey:key];
-        }
+    /// Sets whether the Web Inspector (and "Inspect Element" in the context menu) is available for
+    /// `WKWebView`s created with this configuration. Defaults to `false`, as with `WKWebView`.
+    ///
+    /// This uses the private `developerExtrasEnabled` preference, set via key-value coding.
+    pub fn enable_developer_extras(&mut self, enabled: bool) {
+        let preferences: id = unsafe { msg_send![&*self.objc, preferences] };
+        let _ = kvc::set_value(preferences, "developerExtrasEnabled", KvcValue::Bool(enabled));
     }
 
     /// Consumes and returns the underlying `WKWebViewConfiguration`.
@@ -79,3 +192,39 @@ impl WebViewConfig {
         &mut *self.objc
     }
 }
+
+/// Creates a `WKUserScript` and adds it to the given `WKUserContentController`. Shared between
+/// configuration-time and runtime injection.
+///
+/// If `world` is provided but content worlds aren't supported on this system, the script is
+/// injected into the page world.
+pub(crate) fn add_user_script(
+    content_controller: id,
+    script: &str,
+    at: InjectAt,
+    main_frame_only: bool,
+    world: Option<&ContentWorld>
+) {
+    let source = NSString::new(script);
+    let at: NSInteger = at.into();
+    let main_frame_only = match main_frame_only {
+        true => YES,
+        false => NO
+    };
+
+    unsafe {
+        let alloc: id = msg_send![class!(WKUserScript), alloc];
+        let user_script: id = match world {
+            Some(world) if ContentWorld::is_supported() => {
+                msg_send![alloc, initWithSource:&*source injectionTime:at forMainFrameOnly:main_frame_only
+                    inContentWorld:world.to_objc()]
+            },
+
+            _ => msg_send![alloc, initWithSource:&*source injectionTime:at forMainFrameOnly:main_frame_only]
+        };
+
+        // The content controller retains the script.
+        let _: () = msg_send![content_controller, addUserScript: user_script];
+        let _: () = msg_send![user_script, release];
+    }
+}
@@ -0,0 +1,65 @@
+//! Wraps `WKContentWorld`, which lets scripts run in a JavaScript namespace isolated from the
+//! page's own scripts - e.g, so that page code can't tamper with a native bridge.
+//!
+//! Content worlds require macOS 11 (iOS 14). On older systems, everything runs in the page world.
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, NSString};
+use crate::utils::os;
+
+/// A JavaScript namespace that scripts can be evaluated or injected into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContentWorld {
+    /// The world the page's own scripts run in. Anything here is visible to (and can be modified
+    /// by) the page.
+    Page,
+
+    /// The default world for app-provided scripts, isolated from the page.
+    DefaultClient,
+
+    /// A named world, isolated from both the page and other named worlds. The same name always
+    /// refers to the same world within a `WKWebView`.
+    Named(String)
+}
+
+impl ContentWorld {
+    /// Returns the page world. See `ContentWorld::Page`.
+    pub fn page() -> Self {
+        ContentWorld::Page
+    }
+
+    /// Returns the default client world. See `ContentWorld::DefaultClient`.
+    pub fn default_client() -> Self {
+        ContentWorld::DefaultClient
+    }
+
+    /// Returns the world with the given name, creating it if needed. See `ContentWorld::Named`.
+    pub fn named(name: &str) -> Self {
+        ContentWorld::Named(name.to_string())
+    }
+
+    /// Returns the (autoreleased) `WKContentWorld` for this world. Only call this if
+    /// `is_supported()` returns `true`.
+    pub(crate) fn to_objc(&self) -> id {
+        unsafe {
+            match self {
+                ContentWorld::Page => msg_send![class!(WKContentWorld), pageWorld],
+                ContentWorld::DefaultClient => msg_send![class!(WKContentWorld), defaultClientWorld],
+                ContentWorld::Named(name) => {
+                    let name = NSString::new(name);
+                    msg_send![class!(WKContentWorld), worldWithName:&*name]
+                }
+            }
+        }
+    }
+
+    /// Whether `WKContentWorld` is available on this system (macOS 11+, iOS 14+).
+    pub(crate) fn is_supported() -> bool {
+        #[cfg(feature = "appkit")]
+        return os::is_minimum_version(11);
+
+        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+        return os::is_minimum_version(14);
+    }
+}
@@ -0,0 +1,90 @@
+//! A plain Rust representation of an `NSHTTPCookie`, for moving cookies in and out of a
+//! `WKWebView`'s data store.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSMutableDictionary, NSString};
+
+/// An HTTP cookie.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cookie {
+    /// The cookie name.
+    pub name: String,
+
+    /// The cookie value.
+    pub value: String,
+
+    /// The domain this cookie applies to (e.g, `example.com`, or `.example.com` to include
+    /// subdomains).
+    pub domain: String,
+
+    /// The path this cookie applies to. Defaults to `/`.
+    pub path: String,
+
+    /// When this cookie expires. `None` makes it a session cookie.
+    pub expires: Option<SystemTime>
+}
+
+impl Cookie {
+    /// Creates a session cookie for the given domain, covering all paths.
+    pub fn new<S: Into<String>>(name: S, value: S, domain: S) -> Self {
+        Cookie {
+            name: name.into(),
+            value: value.into(),
+            domain: domain.into(),
+            path: "/".to_string(),
+            expires: None
+        }
+    }
+
+    /// Builds an (autoreleased) `NSHTTPCookie` from this cookie. This can return `nil` if the
+    /// properties are invalid - e.g, an empty name or domain.
+    pub(crate) fn to_objc(&self) -> id {
+        let mut properties = NSMutableDictionary::new();
+
+        // These are the values of the `NSHTTPCookie*` property key constants.
+        properties.insert(NSString::new("Name"), &mut *NSString::new(&self.name));
+        properties.insert(NSString::new("Value"), &mut *NSString::new(&self.value));
+        properties.insert(NSString::new("Domain"), &mut *NSString::new(&self.domain));
+        properties.insert(NSString::new("Path"), &mut *NSString::new(&self.path));
+
+        if let Some(expires) = self.expires {
+            let seconds = expires
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs_f64())
+                .unwrap_or(0.);
+
+            unsafe {
+                let date: id = msg_send![class!(NSDate), dateWithTimeIntervalSince1970: seconds];
+                properties.insert(NSString::new("Expires"), date);
+            }
+        }
+
+        unsafe { msg_send![class!(NSHTTPCookie), cookieWithProperties:&*properties] }
+    }
+
+    /// Converts an `NSHTTPCookie` into a `Cookie`.
+    pub(crate) fn from_objc(cookie: id) -> Self {
+        unsafe {
+            let expires: id = msg_send![cookie, expiresDate];
+
+            let expires = match expires == nil {
+                true => None,
+                false => {
+                    let seconds: f64 = msg_send![expires, timeIntervalSince1970];
+                    Some(UNIX_EPOCH + Duration::from_secs_f64(seconds.max(0.)))
+                }
+            };
+
+            Cookie {
+                name: NSString::retain(msg_send![cookie, name]).to_string(),
+                value: NSString::retain(msg_send![cookie, value]).to_string(),
+                domain: NSString::retain(msg_send![cookie, domain]).to_string(),
+                path: NSString::retain(msg_send![cookie, path]).to_string(),
+                expires
+            }
+        }
+    }
+}
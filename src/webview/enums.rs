@@ -103,7 +103,7 @@ impl From<NavigationResponsePolicy> for NSInteger {
     }
 }
 
-/// Dictates where a given user script should be injected.
+/// Dictates where a given user script should be injected. Maps to `WKUserScriptInjectionTime`.
 #[derive(Clone, Copy, Debug)]
 pub enum InjectAt {
     /// Inject at the start of the document.
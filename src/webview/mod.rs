@@ -13,28 +13,60 @@
 //! Apple does not ship `WKWebView` on tvOS, and as a result this control is not provided on that
 //! platform.
 
+use block::ConcreteBlock;
 use core_graphics::geometry::CGRect;
 
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
-use crate::foundation::{id, nil, NSString, NO, YES};
+use crate::error::Error;
+use crate::foundation::kvc::{self, KvcValue};
+use crate::foundation::{
+    id, nil, to_bool, KeyValueObserver, NSArray, NSData, NSKeyValueObservingOption, NSNumber, NSString, ObserverToken, BOOL, NO,
+    YES
+};
 use crate::geometry::Rect;
+use crate::image::Image;
 use crate::layer::Layer;
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
+use crate::utils::{clear_ptr, load_opt, os};
 
 #[cfg(feature = "autolayout")]
-use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY, SafeAreaLayoutGuide};
+
+#[cfg(feature = "appkit")]
+use core_graphics::base::CGFloat;
+
+#[cfg(feature = "appkit")]
+use core_graphics::geometry::CGPoint;
+
+#[cfg(feature = "appkit")]
+use crate::appkit::printing::PrintSettings;
+
+#[cfg(feature = "appkit")]
+use crate::geometry::Point;
+
+#[cfg(feature = "appkit")]
+use crate::defaults::{UserDefaults, Value};
 
 mod actions;
 pub use actions::*;
 
+mod channel;
+pub use channel::JsMessage;
+
 mod config;
 pub use config::WebViewConfig;
 
+mod content_world;
+pub use content_world::ContentWorld;
+
+mod cookie;
+pub use cookie::Cookie;
+
 mod enums;
 pub use enums::*;
 
@@ -46,13 +78,44 @@ mod mimetype;
 mod traits;
 pub use traits::WebViewDelegate;
 
+mod value;
+pub use value::JsValue;
+
 pub(crate) static WEBVIEW_DELEGATE_PTR: &str = "rstWebViewDelegatePtr";
 
-fn allocate_webview(mut config: WebViewConfig, objc_delegate: Option<&Object>) -> id {
+/// Whether `WKWebView` supports the `interactionState` property (macOS 12+, iOS 15+).
+fn supports_interaction_state() -> bool {
+    #[cfg(feature = "appkit")]
+    return os::is_minimum_version(12);
+
+    #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+    return os::is_minimum_version(15);
+}
+
+/// The smallest magnification (or page zoom) factor we'll apply; anything lower renders pages
+/// unreadably small, and zero or negative values are degenerate.
+#[cfg(feature = "appkit")]
+const MINIMUM_ZOOM: f64 = 0.25;
+
+/// The `UserDefaults` key used to persist the magnification for a given identifier.
+#[cfg(feature = "appkit")]
+fn magnification_key(identifier: &str) -> String {
+    format!("CacaoWebViewMagnification-{}", identifier)
+}
+
+/// Injected when `WebViewConfig::allows_context_menu` is `false`.
+static DISABLE_CONTEXT_MENU_SCRIPT: &str = "document.addEventListener('contextmenu', function(e) { e.preventDefault(); }, true);";
+
+fn allocate_webview(mut config: WebViewConfig, objc_delegate: Option<&Object>, frame: Rect) -> id {
     unsafe {
         // Not a fan of this, but we own it anyway, so... meh.
         let handlers = std::mem::take(&mut config.handlers);
         let protocols = std::mem::take(&mut config.protocols);
+
+        if !config.allows_context_menu {
+            config.add_user_script(DISABLE_CONTEXT_MENU_SCRIPT, InjectAt::Start, false);
+        }
+
         let configuration = config.into_inner();
 
         if let Some(delegate) = &objc_delegate {
@@ -76,9 +139,9 @@ fn allocate_webview(mut config: WebViewConfig, objc_delegate: Option<&Object>) -
             }
         }
 
-        let zero: CGRect = Rect::zero().into();
+        let frame: CGRect = frame.into();
         let webview_alloc: id = msg_send![register_webview_class(), alloc];
-        let webview: id = msg_send![webview_alloc, initWithFrame:zero configuration:configuration];
+        let webview: id = msg_send![webview_alloc, initWithFrame:frame configuration:configuration];
 
         #[cfg(feature = "appkit")]
         let _: () = msg_send![webview, setWantsLayer: YES];
@@ -95,6 +158,31 @@ fn allocate_webview(mut config: WebViewConfig, objc_delegate: Option<&Object>) -
     }
 }
 
+/// Observes `estimatedProgress` on the `WKWebView` backing `view`, forwarding changes to the
+/// delegate held by `objc_delegate`. The delegate is looked up on each change, so this won't call
+/// into a delegate that's been dropped.
+fn observe_progress<T: WebViewDelegate + 'static>(view: &WebView<T>, objc_delegate: &ShareId<Object>) -> Option<ObserverToken> {
+    let objc_delegate = objc_delegate.clone();
+    let options = [NSKeyValueObservingOption::New];
+
+    let webview = view.objc.get(|obj| obj as *const Object as id);
+
+    // WebKit only updates `estimatedProgress` on the main thread, and the token is dropped along
+    // with the `WebView`, which is itself main-thread only.
+    unsafe {
+        KeyValueObserver::observe_unchecked(webview, "estimatedProgress", &options, move |change| {
+            let progress = match change.get("new") {
+                Some(value) if NSNumber::is(value) => NSNumber::retain(value).as_f64(),
+                _ => return
+            };
+
+            if let Some(delegate) = load_opt::<T>(&objc_delegate, WEBVIEW_DELEGATE_PTR) {
+                delegate.did_update_progress(progress);
+            }
+        })
+    }
+}
+
 pub struct WebView<T = ()> {
     /// An internal flag for whether an instance of a View<T> is a handle. Typically, there's only
     /// one instance that should have this set to `false` - if that one drops, we need to know to
@@ -114,6 +202,14 @@ pub struct WebView<T = ()> {
     /// A pointer to the delegate for this view.
     pub delegate: Option<Box<T>>,
 
+    /// Observes `estimatedProgress`, for `WebViewDelegate::did_update_progress()`. Removed when
+    /// the view is dropped.
+    progress_observer: Option<ObserverToken>,
+
+    /// A property containing safe layout guides.
+    #[cfg(feature = "autolayout")]
+    pub safe_layout_guide: SafeAreaLayoutGuide,
+
     /// A pointer to the Objective-C runtime top layout constraint.
     #[cfg(feature = "autolayout")]
     pub top: LayoutAnchorY,
@@ -167,6 +263,9 @@ impl WebView {
     ///
     /// This handles grabbing autolayout anchor pointers, as well as things related to layering and
     /// so on. It returns a generic `WebView<T>`, which the caller can then customize as needed.
+    ///
+    /// `view` must already be retained (e.g, from `new`, or `alloc`/`init`); the returned value
+    /// takes over that ownership rather than retaining it again.
     pub(crate) fn init<T>(view: id) -> WebView<T> {
         unsafe {
             let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
@@ -179,6 +278,10 @@ impl WebView {
             is_handle: false,
             delegate: None,
             objc_delegate: None,
+            progress_observer: None,
+
+            #[cfg(feature = "autolayout")]
+            safe_layout_guide: SafeAreaLayoutGuide::new(view),
 
             #[cfg(feature = "autolayout")]
             top: LayoutAnchorY::top(view),
@@ -210,15 +313,15 @@ impl WebView {
             #[cfg(feature = "autolayout")]
             center_y: LayoutAnchorY::center(view),
 
-            layer: Layer::wrap(unsafe { msg_send![view, layer] }),
+            layer: Layer::from_unretained(unsafe { msg_send![view, layer] }),
 
-            objc: ObjcProperty::retain(view)
+            objc: ObjcProperty::from_retained(view)
         }
     }
 
     /// Returns a default `WebView`, suitable for customizing and displaying.
     pub fn new(config: WebViewConfig) -> Self {
-        let view = allocate_webview(config, None);
+        let view = allocate_webview(config, None, Rect::zero());
         WebView::init(view)
     }
 }
@@ -230,6 +333,22 @@ where
     /// Initializes a new WebView with a given `WebViewDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(config: WebViewConfig, delegate: T) -> WebView<T> {
+        WebView::with_delegate(config, delegate, None)
+    }
+
+    /// Initializes a new WebView with a given `WebViewDelegate` and an initial frame. This is
+    /// useful when you're not using AutoLayout, or when embedding somewhere that needs a real size
+    /// before the first layout pass.
+    ///
+    /// Views created this way translate their autoresizing mask into constraints, so you can
+    /// continue to use `set_frame()` to position them.
+    pub fn with_frame(config: WebViewConfig, delegate: T, frame: Rect) -> WebView<T> {
+        WebView::with_delegate(config, delegate, Some(frame))
+    }
+
+    /// Shared initialization for delegate-backed WebViews. If a frame is provided, the view is
+    /// configured for frame-based layout.
+    fn with_delegate(config: WebViewConfig, delegate: T, frame: Option<Rect>) -> WebView<T> {
         let delegate_class = register_webview_delegate_class(&delegate);
         let mut delegate = Box::new(delegate);
 
@@ -237,14 +356,22 @@ where
             let objc_delegate: id = msg_send![delegate_class, new];
             let ptr: *const T = &*delegate;
             (&mut *objc_delegate).set_ivar(WEBVIEW_DELEGATE_PTR, ptr as usize);
-            ShareId::from_ptr(objc_delegate)
+            ShareId::from_retained_ptr(objc_delegate)
         };
 
-        let view = allocate_webview(config, Some(&objc_delegate));
+        let view = allocate_webview(config, Some(&objc_delegate), frame.unwrap_or_else(Rect::zero));
         let mut view = WebView::init(view);
 
+        #[cfg(feature = "autolayout")]
+        if frame.is_some() {
+            view.set_translates_autoresizing_mask_into_constraints(true);
+        }
+
+        view.progress_observer = observe_progress::<T>(&view, &objc_delegate);
+
         &delegate.did_load(view.clone_as_handle());
         view.delegate = Some(delegate);
+        view.objc_delegate = Some(objc_delegate);
         view
     }
 }
@@ -261,6 +388,10 @@ impl<T> WebView<T> {
             layer: self.layer.clone(),
             objc: self.objc.clone(),
             objc_delegate: None,
+            progress_observer: None,
+
+            #[cfg(feature = "autolayout")]
+            safe_layout_guide: self.safe_layout_guide.clone(),
 
             #[cfg(feature = "autolayout")]
             top: self.top.clone(),
@@ -294,13 +425,19 @@ impl<T> WebView<T> {
         }
     }
 
-    /// Given a URL, instructs the WebView to load it.
+    /// Given a URL, instructs the WebView to load it. If `url` can't be parsed into an `NSURL`,
+    /// this does nothing.
     //  @TODO: Make this take Url instead? Fine for testing now I suppose.
     pub fn load_url(&self, url: &str) {
         let url = NSString::new(url);
 
         self.objc.with_mut(|obj| unsafe {
             let u: id = msg_send![class!(NSURL), URLWithString:&*url];
+
+            if u == nil {
+                return;
+            }
+
             let request: id = msg_send![class!(NSURLRequest), requestWithURL: u];
             let _: () = msg_send![&*obj, loadRequest: request];
         });
@@ -309,12 +446,22 @@ impl<T> WebView<T> {
     /// Given a HTML string, instructs the WebView to load it.
     /// Useful for small html files, but often better to use custom protocol.
     pub fn load_html(&self, html_string: &str) {
-        let html = NSString::new(html_string);
-        let blank = NSString::no_copy("");
+        self.load_html_string(html_string, None);
+    }
+
+    /// Given a HTML string, instructs the WebView to load it. Relative URLs in the HTML (e.g, for
+    /// images or stylesheets) are resolved against `base_url`, if one is provided.
+    pub fn load_html_string(&self, html: &str, base_url: Option<&str>) {
+        let html = NSString::new(html);
+        let base_url = base_url.map(NSString::new);
 
         self.objc.with_mut(|obj| unsafe {
-            let empty: id = msg_send![class!(NSURL), URLWithString:&*blank];
-            let _: () = msg_send![&*obj, loadHTMLString:&*html baseURL:empty];
+            let base: id = match &base_url {
+                Some(url) => msg_send![class!(NSURL), URLWithString:&**url],
+                None => nil
+            };
+
+            let _: () = msg_send![&*obj, loadHTMLString:&*html baseURL:base];
         });
     }
 
@@ -331,6 +478,581 @@ impl<T> WebView<T> {
             let _: () = msg_send![&*obj, goForward];
         });
     }
+
+    /// Returns whether there's a page to go back to - e.g, for enabling a toolbar button. This is
+    /// `false` if nothing has been loaded yet.
+    pub fn can_go_back(&self) -> bool {
+        self.objc.get(|obj| unsafe {
+            let result: BOOL = msg_send![obj, canGoBack];
+            to_bool(result)
+        })
+    }
+
+    /// Returns whether there's a page to go forward to. This is `false` if nothing has been
+    /// loaded yet.
+    pub fn can_go_forward(&self) -> bool {
+        self.objc.get(|obj| unsafe {
+            let result: BOOL = msg_send![obj, canGoForward];
+            to_bool(result)
+        })
+    }
+
+    /// Stops loading the current page, if it's still loading.
+    pub fn stop_loading(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![&*obj, stopLoading];
+        });
+    }
+
+    /// Reloads the current page. This is also the usual way to recover after the web content
+    /// process has been terminated; see `WebViewDelegate::web_content_process_did_terminate()`.
+    pub fn reload(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![&*obj, reload];
+        });
+    }
+
+    /// Serializes the back/forward list (and scroll position) of this WebView, for persisting
+    /// and later restoring via `restore_session_state()` - e.g, to bring back browser tabs across
+    /// launches. The returned bytes are opaque.
+    ///
+    /// This uses `interactionState`, which requires macOS 12 (iOS 15). On older systems, this
+    /// falls back to just the current URL. Returns `None` if nothing has been loaded.
+    pub fn session_state(&self) -> Option<Vec<u8>> {
+        self.objc.get(|obj| unsafe {
+            if supports_interaction_state() {
+                let state: id = msg_send![obj, interactionState];
+
+                return match !state.is_null() && NSData::is(state) {
+                    true => Some(NSData::retain(state).into_vec()),
+                    false => None
+                };
+            }
+
+            let url: id = msg_send![obj, URL];
+            if url.is_null() {
+                return None;
+            }
+
+            NSString::to_string_opt(msg_send![url, absoluteString]).map(String::into_bytes)
+        })
+    }
+
+    /// Restores state previously returned from `session_state()`, navigating to wherever the
+    /// WebView was. This should be called on a WebView that hasn't loaded anything yet.
+    pub fn restore_session_state(&self, state: &[u8]) {
+        if supports_interaction_state() {
+            let data = NSData::with_slice(state);
+
+            self.objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, setInteractionState:&*data];
+            });
+
+            return;
+        }
+
+        if let Ok(url) = std::str::from_utf8(state) {
+            self.load_url(url);
+        }
+    }
+
+    /// Adds a user script to the live `WKWebView`, e.g for feature-flagged enhancements that are
+    /// decided after the view has been created. See `WebViewConfig::add_user_script()` for the
+    /// configuration-time equivalent.
+    ///
+    /// Note that user scripts are only injected when a document loads, so this takes effect on
+    /// the _next_ navigation (or reload); it does not run in the current page. If you need that,
+    /// evaluate the script directly as well.
+    pub fn add_user_script_now(&self, script: &str, at: InjectAt, main_frame_only: bool) {
+        self.objc.with_mut(|obj| {
+            let content_controller: id = unsafe {
+                let configuration: id = msg_send![obj, configuration];
+                msg_send![configuration, userContentController]
+            };
+
+            config::add_user_script(content_controller, script, at, main_frame_only, None);
+        });
+    }
+
+    /// Evaluates `script` in the currently loaded page, and calls `handler` on the main thread with
+    /// the result - e.g, to read form values out of a page you're embedding.
+    ///
+    /// Strings, numbers, Booleans, arrays and plain objects are bridged into the matching
+    /// `JsValue`; `null` and `undefined` come back as `JsValue::Null`. Evaluation errors (e.g, an
+    /// exception being thrown, or the script returning a type that can't be bridged, such as a
+    /// function) are passed as `Error::NSError`.
+    pub fn evaluate_javascript<F>(&self, script: &str, handler: F)
+    where
+        F: Fn(Result<JsValue, Error>) + 'static
+    {
+        let script = NSString::new(script);
+
+        // The copied block owns `handler`, and WebKit releases the block once it's been called.
+        let completion = ConcreteBlock::new(move |result: id, error: id| match error {
+            e if e != nil => handler(Err(Error::new(e))),
+            _ => handler(Ok(JsValue::from_objc(result)))
+        });
+        let completion = completion.copy();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, evaluateJavaScript:&*script completionHandler:&*completion];
+        });
+    }
+
+    /// Asynchronously captures the WebView's contents as an `Image` - e.g, for a link preview -
+    /// and calls `handler` with it on the main thread. `rect` is in the WebView's coordinate
+    /// space; pass `None` to capture the full visible area.
+    ///
+    /// Requires macOS 10.13 (iOS 11). Failures (e.g, if the web content process has crashed) are
+    /// bridged over as `Error::NSError`.
+    pub fn take_snapshot<F>(&self, rect: Option<Rect>, handler: F)
+    where
+        F: Fn(Result<Image, Error>) + 'static
+    {
+        // As with `evaluate_javascript()`, WebKit releases the copied block (and with it,
+        // `handler`) once it's been called.
+        let completion = ConcreteBlock::new(move |image: id, error: id| match error {
+            e if e != nil => handler(Err(Error::new(e))),
+            _ if image == nil => handler(Err(Error::NilPointer)),
+            _ => handler(Ok(Image::with(image)))
+        });
+        let completion = completion.copy();
+
+        unsafe {
+            let configuration: id = msg_send![class!(WKSnapshotConfiguration), new];
+
+            if let Some(rect) = rect {
+                let rect: CGRect = rect.into();
+                let _: () = msg_send![configuration, setRect: rect];
+            }
+
+            self.objc.with_mut(|obj| {
+                let _: () = msg_send![obj, takeSnapshotWithConfiguration:configuration completionHandler:&*completion];
+            });
+
+            let _: () = msg_send![configuration, release];
+        }
+    }
+
+    /// Evaluates `script` in the given content world - e.g, to call into a bridge script that
+    /// was injected into an isolated world via `WebViewConfig::add_user_script_in_world()`.
+    ///
+    /// `handler` is called on the main thread with the result, bridged into a `JsValue` as with
+    /// `evaluate_javascript()`. Evaluation errors (e.g, an exception being thrown) are bridged
+    /// over as `Error::NSError`.
+    ///
+    /// Content worlds require macOS 11 (iOS 14); on older systems, the script is evaluated in
+    /// the page world.
+    pub fn evaluate_javascript_in_world<F>(&self, script: &str, world: ContentWorld, handler: F)
+    where
+        F: Fn(Result<JsValue, Error>) + 'static
+    {
+        let script = NSString::new(script);
+        let completion = ConcreteBlock::new(move |result: id, error: id| match error {
+            e if e != nil => handler(Err(Error::new(e))),
+            _ => handler(Ok(JsValue::from_objc(result)))
+        });
+        let completion = completion.copy();
+
+        self.objc.with_mut(|obj| unsafe {
+            match ContentWorld::is_supported() {
+                true => {
+                    let _: () = msg_send![obj, evaluateJavaScript:&*script inFrame:nil inContentWorld:world.to_objc()
+                        completionHandler:&*completion];
+                },
+
+                false => {
+                    let _: () = msg_send![obj, evaluateJavaScript:&*script completionHandler:&*completion];
+                }
+            }
+        });
+    }
+
+    /// Removes all user scripts from the live `WKWebView` - including any added via
+    /// `WebViewConfig`, such as the script used to disable the context menu.
+    ///
+    /// As with adding, this takes effect on the _next_ navigation; anything already injected into
+    /// the current page remains.
+    pub fn remove_all_user_scripts(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let configuration: id = msg_send![obj, configuration];
+            let content_controller: id = msg_send![configuration, userContentController];
+            let _: () = msg_send![content_controller, removeAllUserScripts];
+        });
+    }
+
+    /// Sets a custom user agent string, which replaces the default one entirely - e.g, for sites
+    /// that serve different content depending on the browser. Passing an empty string resets to
+    /// the default user agent.
+    pub fn set_custom_user_agent(&self, user_agent: &str) {
+        let user_agent = NSString::new(user_agent);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setCustomUserAgent:&*user_agent];
+        });
+    }
+
+    /// Returns the custom user agent string, if one has been set via `set_custom_user_agent()`.
+    pub fn user_agent(&self) -> Option<String> {
+        self.objc.get(|obj| {
+            let user_agent: id = unsafe { msg_send![obj, customUserAgent] };
+
+            match NSString::to_string_opt(user_agent) {
+                Some(user_agent) if !user_agent.is_empty() => Some(user_agent),
+                _ => None
+            }
+        })
+    }
+
+    /// Asynchronously fetches every cookie in this WebView's data store (e.g, a session cookie
+    /// after a login flow), and calls `handler` with them on the main thread.
+    pub fn get_cookies<F>(&self, handler: F)
+    where
+        F: Fn(Vec<Cookie>) + 'static
+    {
+        // WebKit copies the block and releases it once it's fired, which drops `handler`.
+        let completion = ConcreteBlock::new(move |cookies: id| {
+            let cookies = match cookies == nil {
+                true => vec![],
+                false => NSArray::retain(cookies).map(Cookie::from_objc)
+            };
+
+            handler(cookies);
+        });
+        let completion = completion.copy();
+
+        self.objc.with_mut(|obj| unsafe {
+            let configuration: id = msg_send![obj, configuration];
+            let data_store: id = msg_send![configuration, websiteDataStore];
+            let cookie_store: id = msg_send![data_store, httpCookieStore];
+            let _: () = msg_send![cookie_store, getAllCookies:&*completion];
+        });
+    }
+
+    /// Stores `cookie` in this WebView's data store. This is asynchronous; it'll be present for
+    /// subsequent requests once WebKit has processed it. Invalid cookies (e.g, with an empty name
+    /// or domain) are ignored.
+    pub fn set_cookie(&self, cookie: Cookie) {
+        let objc_cookie = cookie.to_objc();
+
+        if objc_cookie == nil {
+            return;
+        }
+
+        self.objc.with_mut(|obj| unsafe {
+            let configuration: id = msg_send![obj, configuration];
+            let data_store: id = msg_send![configuration, websiteDataStore];
+            let cookie_store: id = msg_send![data_store, httpCookieStore];
+            let _: () = msg_send![cookie_store, setCookie:objc_cookie completionHandler:nil];
+        });
+    }
+
+    /// Removes all website data (cookies, caches, local storage, and so on) from this WebView's
+    /// data store, then calls `handler` on the main thread once done.
+    pub fn clear_all_website_data<F>(&self, handler: F)
+    where
+        F: Fn() + 'static
+    {
+        let completion = ConcreteBlock::new(move || handler());
+        let completion = completion.copy();
+
+        self.objc.with_mut(|obj| unsafe {
+            let configuration: id = msg_send![obj, configuration];
+            let data_store: id = msg_send![configuration, websiteDataStore];
+            let types: id = msg_send![class!(WKWebsiteDataStore), allWebsiteDataTypes];
+            let since: id = msg_send![class!(NSDate), distantPast];
+            let _: () = msg_send![data_store, removeDataOfTypes:types modifiedSince:since completionHandler:&*completion];
+        });
+    }
+
+    /// Sets whether the WebView draws its (opaque, white) background. Set this to `false` to get a
+    /// transparent WebView - e.g, for layering web content over native content, or to avoid a
+    /// white flash before the first page has loaded.
+    ///
+    /// On macOS, `WKWebView` has no public API for this; the long-standing (and stable, as of
+    /// macOS 10.10+) approach is to set the private `drawsBackground` property via key-value
+    /// coding, which is what this does. On iOS, the public `opaque` property is used. In both
+    /// cases, the backing layer's `opaque` flag is updated to match.
+    pub fn set_draws_background(&self, draws_background: bool) {
+        self.objc.with_mut(|obj| {
+            #[cfg(feature = "appkit")]
+            let _ = kvc::set_value(obj, "drawsBackground", KvcValue::Bool(draws_background));
+
+            #[cfg(feature = "uikit")]
+            let _: () = unsafe {
+                msg_send![obj, setOpaque:match draws_background {
+                    true => YES,
+                    false => NO
+                }]
+            };
+        });
+
+        self.layer.objc.with_mut(|layer| unsafe {
+            let _: () = msg_send![layer, setOpaque:match draws_background {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Asynchronously fetches the current scroll position of the page (`window.scrollX` and
+    /// `window.scrollY`), and calls `handler` with it.
+    ///
+    /// `WKWebView` has no native scroll API on macOS, so this is done via JavaScript. If nothing
+    /// has been loaded yet (or the script fails), the handler receives `(0., 0.)`.
+    pub fn scroll_position<F>(&self, handler: F)
+    where
+        F: Fn((f64, f64)) + 'static
+    {
+        let has_content = self.objc.get(|obj| unsafe {
+            let url: id = msg_send![obj, URL];
+            url != nil
+        });
+
+        if !has_content {
+            handler((0., 0.));
+            return;
+        }
+
+        let script = NSString::new("[window.scrollX, window.scrollY]");
+        let completion = ConcreteBlock::new(move |result: id, error: id| {
+            if error != nil || result == nil {
+                handler((0., 0.));
+                return;
+            }
+
+            let position = NSArray::retain(result).map(|value| match NSNumber::is(value) {
+                true => NSNumber::retain(value).as_f64(),
+                false => 0.
+            });
+
+            match position.as_slice() {
+                [x, y] => handler((*x, *y)),
+                _ => handler((0., 0.))
+            }
+        });
+        let completion = completion.copy();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, evaluateJavaScript:&*script completionHandler:&*completion];
+        });
+    }
+
+    /// Sets whether the user can magnify the page (e.g, by pinching on a trackpad). Defaults to
+    /// `false`. Programmatic magnification works regardless.
+    #[cfg(feature = "appkit")]
+    pub fn set_allows_magnification(&self, allows: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAllowsMagnification:match allows {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Returns the current magnification factor, where `1.0` is actual size.
+    #[cfg(feature = "appkit")]
+    pub fn magnification(&self) -> f64 {
+        self.objc.get(|obj| unsafe {
+            let magnification: CGFloat = msg_send![obj, magnification];
+            magnification as f64
+        })
+    }
+
+    /// Sets the magnification factor, where `1.0` is actual size. Unlike CSS zoom (or
+    /// `set_page_zoom()`), this scales the rendered page without reflowing it. Factors below
+    /// `0.25` are clamped.
+    #[cfg(feature = "appkit")]
+    pub fn set_magnification(&self, magnification: f64) {
+        let magnification = magnification.max(MINIMUM_ZOOM) as CGFloat;
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setMagnification: magnification];
+        });
+    }
+
+    /// Sets the magnification factor, keeping `point` (in the WebView's coordinate space) fixed
+    /// on screen - e.g, to zoom in on where the user clicked. See `set_magnification()`.
+    #[cfg(feature = "appkit")]
+    pub fn set_magnification_centered_at(&self, magnification: f64, point: Point) {
+        let magnification = magnification.max(MINIMUM_ZOOM) as CGFloat;
+        let point: CGPoint = point.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setMagnification:magnification centeredAtPoint:point];
+        });
+    }
+
+    /// Returns the current page zoom factor, where `1.0` is actual size. This is always `1.0`
+    /// prior to macOS 11.
+    #[cfg(feature = "appkit")]
+    pub fn page_zoom(&self) -> f64 {
+        if !os::is_minimum_version(11) {
+            return 1.;
+        }
+
+        self.objc.get(|obj| unsafe {
+            let zoom: CGFloat = msg_send![obj, pageZoom];
+            zoom as f64
+        })
+    }
+
+    /// Sets the page zoom factor, where `1.0` is actual size. Factors below `0.25` are clamped.
+    ///
+    /// Unlike magnification, which scales the rendered page like an image, page zoom behaves like
+    /// browser zoom: content is laid out again at the new size, so text reflows to fit the width
+    /// of the WebView. This is generally what you want for accessibility. Requires macOS 11; it's
+    /// a noop on older systems.
+    #[cfg(feature = "appkit")]
+    pub fn set_page_zoom(&self, zoom: f64) {
+        if !os::is_minimum_version(11) {
+            return;
+        }
+
+        let zoom = zoom.max(MINIMUM_ZOOM) as CGFloat;
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setPageZoom: zoom];
+        });
+    }
+
+    /// Resets magnification to actual size.
+    #[cfg(feature = "appkit")]
+    pub fn actual_size(&self) {
+        self.set_magnification(1.);
+    }
+
+    /// Magnifies the page so that its full width fits within the WebView. The page width is read
+    /// via JavaScript, so this applies asynchronously; it's a noop if nothing has been loaded yet.
+    #[cfg(feature = "appkit")]
+    pub fn zoom_to_fit(&self) {
+        let webview = self
+            .objc
+            .get(|obj| unsafe { ShareId::<Object>::from_ptr(msg_send![obj, self]) });
+
+        let has_content: bool = unsafe {
+            let url: id = msg_send![&*webview, URL];
+            url != nil
+        };
+
+        if !has_content {
+            return;
+        }
+
+        let script = NSString::new("document.documentElement.scrollWidth");
+        let completion = ConcreteBlock::new(move |result: id, error: id| {
+            if error != nil || result == nil || !NSNumber::is(result) {
+                return;
+            }
+
+            let content_width = NSNumber::retain(result).as_f64();
+            if content_width <= 0. {
+                return;
+            }
+
+            unsafe {
+                let bounds: CGRect = msg_send![&*webview, bounds];
+                let magnification = bounds.size.width / content_width as CGFloat;
+                let _: () = msg_send![&*webview, setMagnification: magnification];
+            }
+        });
+        let completion = completion.copy();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, evaluateJavaScript:&*script completionHandler:&*completion];
+        });
+    }
+
+    /// Stores the current magnification in the standard `UserDefaults`, keyed by `identifier` -
+    /// e.g, a document path or ID. Pair with `restore_magnification()`.
+    #[cfg(feature = "appkit")]
+    pub fn save_magnification(&self, identifier: &str) {
+        let mut defaults = UserDefaults::standard();
+        defaults.insert(magnification_key(identifier), Value::Float(self.magnification()));
+    }
+
+    /// Restores a magnification previously stored via `save_magnification()` for `identifier`.
+    /// Returns whether one was found.
+    #[cfg(feature = "appkit")]
+    pub fn restore_magnification(&self, identifier: &str) -> bool {
+        let defaults = UserDefaults::standard();
+
+        match defaults.get(magnification_key(identifier)).and_then(|value| value.as_f64()) {
+            Some(magnification) => {
+                self.set_magnification(magnification);
+                true
+            },
+
+            None => false
+        }
+    }
+
+    /// Prints the page, showing the standard print panel. See `print_with_settings()`.
+    #[cfg(feature = "appkit")]
+    pub fn print(&self) {
+        self.print_with_settings(PrintSettings::default());
+    }
+
+    /// Prints the page with the given settings (e.g, orientation and margins), showing the
+    /// standard print panel. If the page hasn't finished loading, whatever's currently rendered is
+    /// printed.
+    ///
+    /// On macOS 11+, this goes through WebKit's own print operation, which paginates the full
+    /// document. That operation has to run as a sheet, so if the WebView isn't in a window (or on
+    /// older systems), we fall back to printing the WebView as a regular view, which only covers
+    /// the visible area.
+    #[cfg(feature = "appkit")]
+    pub fn print_with_settings(&self, settings: PrintSettings) {
+        self.objc.with_mut(|obj| unsafe {
+            let print_info = settings.to_print_info();
+            let window: id = msg_send![obj, window];
+
+            // WebKit's operation can't be run synchronously, and hangs (or prints a blank page) if
+            // it is - so it's only usable when we can run it as a sheet on the WebView's window.
+            let operation: id = match os::is_minimum_version(11) && window != nil {
+                true => {
+                    let operation: id = msg_send![obj, printOperationWithPrintInfo: print_info];
+
+                    // WebKit's print view needs a frame, or nothing gets drawn.
+                    let bounds: CGRect = msg_send![obj, bounds];
+                    let view: id = msg_send![operation, view];
+                    let _: () = msg_send![view, setFrame: bounds];
+                    operation
+                },
+
+                false => msg_send![class!(NSPrintOperation), printOperationWithView:obj printInfo:print_info]
+            };
+
+            let _: () = msg_send![print_info, release];
+
+            match window == nil {
+                true => {
+                    let _: BOOL = msg_send![operation, runOperation];
+                },
+
+                false => {
+                    let _: () = msg_send![operation, runOperationModalForWindow:window
+                        delegate:nil
+                        didRunSelector:nil
+                        contextInfo:nil];
+                }
+            }
+        });
+    }
+
+    /// Scrolls the page to the given position, via `window.scrollTo()`. This is a noop if nothing
+    /// has been loaded yet.
+    pub fn set_scroll_position(&self, x: f64, y: f64) {
+        let script = NSString::new(&format!("window.scrollTo({}, {});", x, y));
+
+        self.objc.with_mut(|obj| unsafe {
+            let url: id = msg_send![obj, URL];
+
+            if url != nil {
+                let _: () = msg_send![obj, evaluateJavaScript:&*script completionHandler:nil];
+            }
+        });
+    }
 }
 
 impl<T> ObjcAccess for WebView<T> {
@@ -364,6 +1086,14 @@ impl<T> Drop for WebView<T> {
                 let _: () = msg_send![&*obj, setUIDelegate: nil];
             });
 
+            self.progress_observer = None;
+
+            // Script message and URL scheme handlers retain the delegate object, so it can outlive
+            // us; make sure it can't call back into a dropped delegate.
+            if let Some(objc_delegate) = &self.objc_delegate {
+                clear_ptr(&**objc_delegate as *const Object as id, WEBVIEW_DELEGATE_PTR);
+            }
+
             self.remove_from_superview();
         }
     }
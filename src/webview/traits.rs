@@ -2,9 +2,10 @@
 //! `WKWebView`. It allows you to do things such as handle opening a file (for uploads or
 //! in-browser-processing), handling navigation actions or JS message callbacks, and so on.
 
+use crate::error::Error;
 use crate::webview::actions::{NavigationAction, NavigationResponse, OpenPanelParameters};
 use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
-use crate::webview::WebView;
+use crate::webview::{JsValue, WebView};
 
 /// You can implement this on structs to handle callbacks from the underlying `WKWebView`.
 pub trait WebViewDelegate {
@@ -44,6 +45,11 @@ pub trait WebViewDelegate {
     /// Note that at the moment, you really should handle bridging JSON/stringification yourself.
     fn on_message(&self, _name: &str, _body: &str) {}
 
+    /// Called alongside `on_message()`, but with the message body bridged into a `JsValue` - so
+    /// that objects and arrays posted from JavaScript come through structured, without needing to
+    /// `JSON.stringify()` them first. Handlers are registered via `WebViewConfig::add_handler()`.
+    fn did_receive_message(&self, _name: &str, _body: JsValue) {}
+
     /// Called when a custom protocol URI is requested.
     fn on_custom_protocol_request(&self, _uri: &str) -> Option<Vec<u8>> {
         None
@@ -61,6 +67,29 @@ pub trait WebViewDelegate {
         handler(NavigationResponsePolicy::Allow);
     }
 
+    /// Called when a navigation (that was allowed by `policy_for_navigation_action()`) has
+    /// started loading.
+    fn did_start_navigation(&self) {}
+
+    /// Called when a navigation has finished loading.
+    fn did_finish_navigation(&self) {}
+
+    /// Called as the estimated progress of the current navigation changes, from `0.0` to `1.0` -
+    /// e.g, for driving a loading bar.
+    fn did_update_progress(&self, _progress: f64) {}
+
+    /// Called when a navigation has failed, either before or after content began arriving (e.g,
+    /// the host couldn't be reached, or the connection dropped).
+    ///
+    /// Note that starting a new navigation while one is in progress will fail the first with
+    /// `NSURLErrorCancelled` (`-999`), which you'll generally want to ignore.
+    fn did_fail_navigation(&self, _error: Error) {}
+
+    /// Called when the web content process backing this view has been terminated - e.g, by the
+    /// system reclaiming memory, or a crash. The view is left blank until something is loaded
+    /// again, so you'll typically want to call `WebView::reload()` (or load your last URL) here.
+    fn web_content_process_did_terminate(&self) {}
+
     /// Given a callback handler and some open panel parameters (e.g, if the user is clicking an
     /// upload field that pre-specifies supported options), you should create a `FileSelectPanel`
     /// and thread the callbacks accordingly.
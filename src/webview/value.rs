@@ -0,0 +1,108 @@
+//! Implements `JsValue`, which is what results from evaluating JavaScript are bridged into.
+
+use std::collections::HashMap;
+
+use objc::runtime::Class;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, to_bool, NSArray, NSDictionary, NSNumber, NSString, BOOL};
+
+/// A value returned from evaluating JavaScript in a `WebView`. This mirrors the set of types that
+/// `WKWebView` can hand back, which is to say: roughly JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsValue {
+    /// `null`, or `undefined`.
+    Null,
+
+    /// A Boolean value.
+    Bool(bool),
+
+    /// A number. All numbers in JavaScript are doubles.
+    Number(f64),
+
+    /// A string.
+    String(String),
+
+    /// An array.
+    Array(Vec<JsValue>),
+
+    /// An object.
+    Object(HashMap<String, JsValue>)
+}
+
+impl JsValue {
+    /// If this is a Bool, it returns the associated bool. Returns `None` otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsValue::Bool(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    /// If this is a Number, it returns the associated `f64`. Returns `None` otherwise.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsValue::Number(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    /// If this is a String, it returns a &str. Returns `None` otherwise.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsValue::String(s) => Some(s),
+            _ => None
+        }
+    }
+
+    /// Returns whether this is `Null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, JsValue::Null)
+    }
+
+    /// Given an `id` handed back from `evaluateJavaScript:completionHandler:` - an `NSString`,
+    /// `NSNumber`, `NSArray`, `NSDictionary`, `NSNull`, or `nil` - converts it to a `JsValue`.
+    pub(crate) fn from_objc(value: id) -> Self {
+        if value == nil || is_kind_of(value, class!(NSNull)) {
+            return JsValue::Null;
+        }
+
+        if NSString::is(value) {
+            return JsValue::String(NSString::retain(value).to_string());
+        }
+
+        // Booleans come through as `__NSCFBoolean`, which reports as a `char`.
+        if NSNumber::is(value) {
+            let number = NSNumber::retain(value);
+
+            return match number.objc_type() {
+                "c" | "B" => JsValue::Bool(number.as_bool()),
+                _ => JsValue::Number(number.as_f64())
+            };
+        }
+
+        if is_kind_of(value, class!(NSArray)) {
+            return JsValue::Array(NSArray::retain(value).map(JsValue::from_objc));
+        }
+
+        if is_kind_of(value, class!(NSDictionary)) {
+            let dictionary = NSDictionary::retain(value);
+
+            return JsValue::Object(
+                dictionary
+                    .iter()
+                    .map(|(key, object)| (key, JsValue::from_objc(object)))
+                    .collect()
+            );
+        }
+
+        // Shouldn't happen, as WebKit errors out on anything else - but just in case.
+        JsValue::String(NSString::retain(unsafe { msg_send![value, description] }).to_string())
+    }
+}
+
+/// Returns whether `value` is an instance of `class` (or a subclass thereof).
+fn is_kind_of(value: id, class: &Class) -> bool {
+    let result: BOOL = unsafe { msg_send![value, isKindOfClass: class] };
+    to_bool(result)
+}